@@ -3,7 +3,7 @@
 count = 0
 last_depth = nil
 
-open('2021/01/input.txt').each_line do |line|
+open('2021/01/data/input.txt').each_line do |line|
   depth = line.to_i
   if !last_depth.nil? && depth > last_depth
     count += 1
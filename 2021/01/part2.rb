@@ -5,7 +5,7 @@ prev2 = nil
 prev1 = nil
 last_depth = nil
 
-open('2021/01/input.txt').each_line do |line|
+open('2021/01/data/input.txt').each_line do |line|
   depth = nil
   current = line.to_i
   if !prev2.nil? && !prev1.nil?
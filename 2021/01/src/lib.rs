@@ -0,0 +1,47 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+/// Number of times the sum of a sliding window of `width` depths increases.
+fn increases(depths: &[u32], width: usize) -> usize {
+    let sums: Vec<u32> = depths.windows(width).map(|w| w.iter().sum()).collect();
+    sums.windows(2).filter(|w| w[1] > w[0]).count()
+}
+
+impl Solution for Day {
+    /// The sonar sweep's depth measurements.
+    type Input<'a> = Vec<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        parse::each_line(input, |l| l.number())
+    }
+
+    /// Number of measurements larger than the previous one.
+    fn part1(depths: &Vec<u32>) -> usize {
+        increases(depths, 1)
+    }
+
+    /// Number of three-measurement sliding windows larger than the previous one.
+    fn part2(depths: &Vec<u32>) -> usize {
+        increases(depths, 3)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(7, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(5, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
@@ -3,7 +3,7 @@ pos = 0
 depth = 0
 aim = 0
 
-open('2021/02/input.txt').each_line do |line|
+open('2021/02/data/input.txt').each_line do |line|
   direction, count = line.split ' '
   case direction
   when 'forward'
@@ -0,0 +1,79 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+fn parse_command(line: &mut Line) -> Result<Command, ParseError> {
+    let start = *line;
+    let direction = line.word()?;
+    line.tag(" ")?;
+    let n = line.number()?;
+    match direction {
+        "forward" => Ok(Command::Forward(n)),
+        "down" => Ok(Command::Down(n)),
+        "up" => Ok(Command::Up(n)),
+        _ => Err(start.error("forward, down or up")),
+    }
+}
+
+impl Solution for Day {
+    /// The planned course.
+    type Input<'a> = Vec<Command>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        parse::each_line(input, parse_command)
+    }
+
+    /// Product of the final horizontal position and depth, when `up` and `down` change the depth.
+    fn part1(commands: &Vec<Command>) -> i64 {
+        let (mut pos, mut depth) = (0, 0);
+        for command in commands {
+            match command {
+                Command::Forward(n) => pos += n,
+                Command::Down(n) => depth += n,
+                Command::Up(n) => depth -= n,
+            }
+        }
+        pos * depth
+    }
+
+    /// Product of the final horizontal position and depth, when `up` and `down` change the aim.
+    fn part2(commands: &Vec<Command>) -> i64 {
+        let (mut pos, mut depth, mut aim) = (0, 0, 0);
+        for command in commands {
+            match command {
+                Command::Forward(n) => {
+                    pos += n;
+                    depth += aim * n;
+                }
+                Command::Down(n) => aim += n,
+                Command::Up(n) => aim -= n,
+            }
+        }
+        pos * depth
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(150, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(900, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
@@ -3,7 +3,7 @@
 counts = nil
 num_lines = 0
 
-open('2021/03/input.txt').each_line do |line|
+open('2021/03/data/input.txt').each_line do |line|
   bits = line.strip.chars.map &:to_i
   if counts.nil?
     counts = bits
@@ -1,6 +1,6 @@
 #!/usr/bin/env ruby
 
-nums = open('2021/03/input.txt').readlines.map { |l| l.strip.chars.map &:to_i }
+nums = open('2021/03/data/input.txt').readlines.map { |l| l.strip.chars.map &:to_i }
 
 num_bits = nums.first.size  # Assume all numbers have the same bit width.
 
@@ -0,0 +1,78 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+fn to_number(bits: &[u8]) -> u32 {
+    bits.iter().fold(0, |n, b| n * 2 + (b - b'0') as u32)
+}
+
+/// Whether `1` is at least as common as `0` in position `i`.
+fn ones_common(numbers: &[&[u8]], i: usize) -> bool {
+    let ones = numbers.iter().filter(|n| n[i] == b'1').count();
+    ones * 2 >= numbers.len()
+}
+
+/// Repeatedly keeps the numbers whose next bit matches the bit chosen by `keep`, given whether
+/// `1` is the most common bit, until only one number is left.
+fn rating(numbers: &[&[u8]], keep: fn(bool) -> u8) -> u32 {
+    let mut numbers = numbers.to_vec();
+    for i in 0..numbers[0].len() {
+        let bit = keep(ones_common(&numbers, i));
+        numbers.retain(|n| n[i] == bit);
+        if numbers.len() == 1 {
+            break;
+        }
+    }
+    to_number(numbers[0])
+}
+
+impl Solution for Day {
+    /// The binary numbers in the diagnostic report.
+    type Input<'a> = Vec<&'a [u8]>;
+    type Part1 = u32;
+    type Part2 = u32;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Vec<&[u8]>, ParseError> {
+        let rows = parse::grid(input, "01")?;
+        Ok(rows.into_iter().map(|row| row.as_bytes()).collect())
+    }
+
+    /// Power consumption: the product of the gamma and epsilon rates.
+    fn part1(numbers: &Vec<&[u8]>) -> u32 {
+        let width = numbers[0].len();
+        let gamma: Vec<u8> = (0..width)
+            .map(|i| {
+                let ones = numbers.iter().filter(|n| n[i] == b'1').count();
+                if ones > numbers.len() / 2 { b'1' } else { b'0' }
+            })
+            .collect();
+        let epsilon: Vec<u8> = gamma.iter().map(|b| b'0' + b'1' - b).collect();
+        to_number(&gamma) * to_number(&epsilon)
+    }
+
+    /// Life support rating: the product of the oxygen generator and CO2 scrubber ratings.
+    fn part2(numbers: &Vec<&[u8]>) -> u32 {
+        let oxygen = rating(numbers, |ones| if ones { b'1' } else { b'0' });
+        let co2 = rating(numbers, |ones| if ones { b'0' } else { b'1' });
+        oxygen * co2
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(198, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(230, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
@@ -1,6 +1,6 @@
 #!/usr/bin/env ruby
 
-input = open('2021/04/input.txt')
+input = open('2021/04/data/input.txt')
 
 numbers = input.readline.strip.split(',').map &:to_i
 
@@ -0,0 +1,151 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use std::collections::HashSet;
+
+pub struct Day;
+
+type Board = [[u32; 5]; 5];
+
+/// The numbers to be drawn, and the bingo boards.
+pub struct Bingo {
+    numbers: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+fn is_winner(marked: &[[bool; 5]; 5]) -> bool {
+    (0..5).any(|i| (0..5).all(|j| marked[i][j]) || (0..5).all(|j| marked[j][i]))
+}
+
+fn unmarked_sum(board: &Board, marked: &[[bool; 5]; 5]) -> u64 {
+    let mut sum = 0;
+    for i in 0..5 {
+        for j in 0..5 {
+            if !marked[i][j] {
+                sum += board[i][j] as u64;
+            }
+        }
+    }
+    sum
+}
+
+/// The final score of each board, in the order they win.
+fn scores(bingo: &Bingo) -> Vec<u64> {
+    let mut marked = vec![[[false; 5]; 5]; bingo.boards.len()];
+    let mut won = vec![false; bingo.boards.len()];
+    let mut scores = vec![];
+    for &number in &bingo.numbers {
+        for (b, board) in bingo.boards.iter().enumerate() {
+            if won[b] {
+                continue;
+            }
+            for i in 0..5 {
+                for j in 0..5 {
+                    if board[i][j] == number {
+                        marked[b][i][j] = true;
+                    }
+                }
+            }
+            if is_winner(&marked[b]) {
+                won[b] = true;
+                scores.push(unmarked_sum(board, &marked[b]) * number as u64);
+            }
+        }
+    }
+    scores
+}
+
+impl Solution for Day {
+    type Input<'a> = Bingo;
+    type Part1 = u64;
+    type Part2 = u64;
+    const SHAPE: Shape = Shape::Blocks;
+
+    fn parse(input: &str) -> Result<Bingo, ParseError> {
+        let blocks = parse::blocks(input);
+        let Some((first, boards)) = blocks.split_first() else {
+            return Err(ParseError::end_of_input(0, "the numbers to draw"));
+        };
+        if let Some(extra) = first.get(1) {
+            return Err(extra.error("a blank line"));
+        }
+        let numbers = first[0].parse(|l| l.list(",", |l| l.number()))?;
+        let boards = boards
+            .iter()
+            .map(|rows| {
+                if let Some(extra) = rows.get(5) {
+                    return Err(extra.error("a blank line"));
+                }
+                if rows.len() < 5 {
+                    let next = rows[rows.len() - 1].line_number() + 1;
+                    return Err(Line::new(next, "").error("a row of the board"));
+                }
+                let mut board = [[0; 5]; 5];
+                for (row, line) in board.iter_mut().zip(rows) {
+                    let cells = line.parse(|l| {
+                        l.spaces();
+                        l.list(" ", |l| {
+                            l.spaces();
+                            l.number()
+                        })
+                    })?;
+                    *row = cells
+                        .try_into()
+                        .map_err(|_| line.error_at(0, "a row of five numbers"))?;
+                }
+                Ok(board)
+            })
+            .collect::<Result<Vec<Board>, _>>()?;
+
+        // Both parts need a board that wins.
+        if boards.is_empty() {
+            return Err(ParseError::end_of_input(input.lines().count(), "a board"));
+        }
+        let drawn: HashSet<u32> = numbers.iter().copied().collect();
+        let wins = |board: &Board| is_winner(&board.map(|row| row.map(|n| drawn.contains(&n))));
+        if !boards.iter().any(wins) {
+            let end = first[0].text().len();
+            return Err(first[0].error_at(end, "numbers that complete a line of some board"));
+        }
+        Ok(Bingo { numbers, boards })
+    }
+
+    /// Final score of the first board to win.
+    fn part1(bingo: &Bingo) -> u64 {
+        scores(bingo)[0]
+    }
+
+    /// Final score of the last board to win.
+    fn part2(bingo: &Bingo) -> u64 {
+        *scores(bingo).last().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(4512, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(1924, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day::parse(input).err().unwrap().to_string();
+        assert_eq!("line 2, column 1: expected a board, found end of input", error("1,2,3\n"));
+        let board = "1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25\n";
+        assert_eq!(
+            "line 1, column 8: expected numbers that complete a line of some board, \
+             found end of line",
+            error(&format!("1,2,3,4\n\n{}", board))
+        );
+        assert!(Day::parse(&format!("1,2,3,4,5\n\n{}", board)).is_ok());
+    }
+}
@@ -3,7 +3,7 @@ import java.io.File
 
 val grid: Array<IntArray> = Array(1000) { IntArray(1000) }
 
-File("2021/05/input.txt").forEachLine {
+File("2021/05/data/input.txt").forEachLine {
   val (start, end) = it.split(" -> ")
   val (x1, y1) = start.split(",").map(String::toInt)
   val (x2, y2) = end.split(",").map(String::toInt)
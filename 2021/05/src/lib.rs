@@ -0,0 +1,71 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use std::collections::HashMap;
+
+pub struct Day;
+
+/// The two ends of a line of vents.
+type Vent = ((i32, i32), (i32, i32));
+
+fn point(line: &mut Line) -> Result<(i32, i32), ParseError> {
+    let x = line.number()?;
+    line.tag(",")?;
+    Ok((x, line.number()?))
+}
+
+/// Number of points covered by at least two lines, optionally counting diagonal lines.
+fn overlaps(vents: &[Vent], diagonals: bool) -> usize {
+    let mut counts: HashMap<(i32, i32), u32> = HashMap::new();
+    for &((x1, y1), (x2, y2)) in vents {
+        if !diagonals && x1 != x2 && y1 != y2 {
+            continue;
+        }
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        let steps = (x2 - x1).abs().max((y2 - y1).abs());
+        for i in 0..=steps {
+            *counts.entry((x1 + i * dx, y1 + i * dy)).or_default() += 1;
+        }
+    }
+    counts.values().filter(|&&c| c >= 2).count()
+}
+
+impl Solution for Day {
+    /// The lines of hydrothermal vents.
+    type Input<'a> = Vec<Vent>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vent>, ParseError> {
+        parse::each_line(input, |l| {
+            let start = point(l)?;
+            l.tag(" -> ")?;
+            Ok((start, point(l)?))
+        })
+    }
+
+    /// Number of points where at least two horizontal or vertical lines overlap.
+    fn part1(vents: &Vec<Vent>) -> usize {
+        overlaps(vents, false)
+    }
+
+    /// Number of points where at least two lines overlap, including diagonal lines.
+    fn part2(vents: &Vec<Vent>) -> usize {
+        overlaps(vents, true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(5, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(12, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
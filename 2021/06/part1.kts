@@ -3,7 +3,7 @@ import java.io.File
 
 var fish = LongArray(9)
 
-for (f in File("2021/06/input.txt").readText().trim().split(",").map(String::toInt)) {
+for (f in File("2021/06/data/input.txt").readText().trim().split(",").map(String::toInt)) {
   fish[f] = fish[f] + 1
 }
 
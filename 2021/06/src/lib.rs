@@ -0,0 +1,65 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+/// Number of lanternfish after `days` days, starting with the given counts of fish by timer.
+fn simulate(mut fish: [u64; 9], days: usize) -> u64 {
+    for _ in 0..days {
+        fish.rotate_left(1);
+        fish[6] += fish[8];
+    }
+    fish.iter().sum()
+}
+
+impl Solution for Day {
+    /// The number of fish with each internal timer value.
+    type Input<'a> = [u64; 9];
+    type Part1 = u64;
+    type Part2 = u64;
+    const SHAPE: Shape = Shape::SingleLine;
+
+    fn parse(input: &str) -> Result<[u64; 9], ParseError> {
+        let mut fish = [0; 9];
+        let timers = parse::single_line(input, |l| {
+            l.list(",", |l| {
+                let start = *l;
+                match l.number::<usize>()? {
+                    t if t < fish.len() => Ok(t),
+                    _ => Err(start.error("a timer from 0 to 8")),
+                }
+            })
+        })?;
+        for t in timers {
+            fish[t] += 1;
+        }
+        Ok(fish)
+    }
+
+    /// Number of lanternfish after 80 days.
+    fn part1(fish: &[u64; 9]) -> u64 {
+        simulate(*fish, 80)
+    }
+
+    /// Number of lanternfish after 256 days.
+    fn part2(fish: &[u64; 9]) -> u64 {
+        simulate(*fish, 256)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(5934, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(26984457539, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
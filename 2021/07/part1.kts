@@ -2,7 +2,7 @@
 import java.io.File
 import kotlin.math.abs
 
-val input = File("2021/07/input.txt").readText().trim().split(",").map(String::toInt).sorted()
+val input = File("2021/07/data/input.txt").readText().trim().split(",").map(String::toInt).sorted()
 
 val median =
   if (input.size % 2 == 0) {
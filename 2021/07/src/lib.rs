@@ -0,0 +1,54 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+/// Fuel needed to move every crab to `target`, where moving `n` steps costs `cost(n)`.
+fn total_cost(crabs: &[i64], target: i64, cost: fn(i64) -> i64) -> i64 {
+    crabs.iter().map(|c| cost((c - target).abs())).sum()
+}
+
+impl Solution for Day {
+    /// The horizontal position of each crab, in order.
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+    const SHAPE: Shape = Shape::SingleLine;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        let mut crabs = parse::single_line(input, |l| l.list(",", |l| l.number()))?;
+        crabs.sort();
+        Ok(crabs)
+    }
+
+    /// Least fuel needed to align the crabs, when each step costs one. The median is optimal.
+    fn part1(crabs: &Vec<i64>) -> i64 {
+        let median = crabs[crabs.len() / 2];
+        total_cost(crabs, median, |n| n)
+    }
+
+    /// Least fuel needed to align the crabs, when each step costs one more than the last.
+    fn part2(crabs: &Vec<i64>) -> i64 {
+        (crabs[0]..=crabs[crabs.len() - 1])
+            .map(|target| total_cost(crabs, target, |n| n * (n + 1) / 2))
+            .min()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(37, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(168, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
@@ -2,7 +2,7 @@
 import java.io.File
 
 var count = 0
-File("2021/08/input.txt").forEachLine {
+File("2021/08/data/input.txt").forEachLine {
   val (_, after) = it.split(" | ").map { it.split(" ") }
   for (digit in after) {
     if (digit.length == 2 ||
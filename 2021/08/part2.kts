@@ -6,7 +6,7 @@ val allSegments = setOf('a', 'b', 'c', 'd', 'e', 'f', 'g')
 fun invertDigit(digit: Set<Char>): Set<Char> = allSegments.subtract(digit)
 
 var total = 0
-File("2021/08/input.txt").forEachLine {
+File("2021/08/data/input.txt").forEachLine {
   val (before, after) = it.split(" | ").map { it.split(" ").map { it.toSet() } }
 
   val unparsedDigits = before.toMutableList()
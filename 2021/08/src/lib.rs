@@ -0,0 +1,108 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+/// The set of segments lit in a digit, as a bitmask with `a` in the lowest bit.
+type Segments = u8;
+
+/// The ten unique signal patterns seen on a display, and the four digits of its output.
+pub struct Display {
+    patterns: Vec<Segments>,
+    output: Vec<Segments>,
+}
+
+fn segments(line: &mut Line) -> Result<Segments, ParseError> {
+    let pattern = line.take_while(|c| ('a'..='g').contains(&c), "a segment from a to g")?;
+    Ok(pattern.bytes().fold(0, |s, b| s | 1 << (b - b'a')))
+}
+
+fn contains(digit: Segments, other: Segments) -> bool {
+    digit & other == other
+}
+
+impl Display {
+    /// The value shown on the display, working out which pattern is which digit from how many
+    /// segments they have and which segments they share with the digits already found.
+    fn value(&self) -> Option<u32> {
+        let mut remaining = self.patterns.clone();
+        let mut digits = [0; 10];
+        let mut find = |digit: usize, test: &dyn Fn(Segments) -> bool| {
+            let i = remaining.iter().position(|&p| test(p))?;
+            digits[digit] = remaining.remove(i);
+            Some(digits[digit])
+        };
+        let len = |p: Segments| p.count_ones();
+
+        let one = find(1, &|p| len(p) == 2)?;
+        find(7, &|p| len(p) == 3)?;
+        let four = find(4, &|p| len(p) == 4)?;
+        find(8, &|p| len(p) == 7)?;
+        // The order matters for the rest: each test relies on earlier digits having been removed.
+        let nine = find(9, &|p| len(p) == 6 && contains(p, four))?;
+        find(0, &|p| len(p) == 6 && contains(p, one))?;
+        find(6, &|p| len(p) == 6)?;
+        find(3, &|p| len(p) == 5 && contains(p, one))?;
+        find(2, &|p| len(p) == 5 && contains(p, !nine & 0x7f))?;
+        find(5, &|p| len(p) == 5)?;
+
+        self.output.iter().try_fold(0, |value, o| {
+            let digit = digits.iter().position(|d| d == o)?;
+            Some(value * 10 + digit as u32)
+        })
+    }
+}
+
+impl Solution for Day {
+    /// The notes taken from each display.
+    type Input<'a> = Vec<Display>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Display>, ParseError> {
+        parse::each_line(input, |l| {
+            let mut patterns = vec![];
+            while !l.eat("| ") {
+                patterns.push(segments(l)?);
+                l.tag(" ")?;
+            }
+            let output = l.list(" ", segments)?;
+            Ok(Display { patterns, output })
+        })
+    }
+
+    /// Number of times the digits 1, 4, 7 or 8, which have a unique number of segments, appear
+    /// in the outputs.
+    fn part1(displays: &Vec<Display>) -> usize {
+        displays
+            .iter()
+            .flat_map(|d| &d.output)
+            .filter(|o| matches!(o.count_ones(), 2 | 3 | 4 | 7))
+            .count()
+    }
+
+    /// Sum of the output values.
+    fn part2(displays: &Vec<Display>) -> u32 {
+        displays
+            .iter()
+            .map(|d| d.value().expect("Inconsistent signal patterns"))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(26, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(5353, Day::part2(&Day::parse(&example!("example2.txt")).unwrap()));
+        assert_eq!(61229, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
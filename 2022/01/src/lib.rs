@@ -0,0 +1,37 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    /// Calories carried by each elf.
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+    const SHAPE: Shape = Shape::Blocks;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        parse::blocks(input)
+            .into_iter()
+            .map(|elf| {
+                elf.into_iter()
+                    .map(|line| line.parse(|l| l.number::<i32>()).map(i64::from))
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Maximum calories carried by a single elf.
+    fn part1(elves: &Vec<i64>) -> i64 {
+        *elves.iter().max().unwrap()
+    }
+
+    /// Total calories carried by the top three elves.
+    fn part2(elves: &Vec<i64>) -> i64 {
+        let mut elves = elves.clone();
+        elves.sort();
+        elves.reverse();
+        elves.iter().take(3).sum::<i64>()
+    }
+}
@@ -0,0 +1,68 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+use std::collections::HashMap;
+
+pub struct Day;
+
+fn total_score(rounds: &[(char, char)], scores: HashMap<(char, char), i32>) -> i32 {
+    let mut total = 0;
+    for round in rounds {
+        total += scores.get(round).unwrap();
+    }
+    total
+}
+
+// Rock = 1
+// Paper = 2
+// Scissors = 3
+
+impl Solution for Day {
+    /// The columns of each round in the strategy guide.
+    type Input<'a> = Vec<(char, char)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<(char, char)>, ParseError> {
+        parse::each_line(input, |line| {
+            let a = line.one_of("ABC")?;
+            line.tag(" ")?;
+            Ok((a, line.one_of("XYZ")?))
+        })
+    }
+
+    /// Total score when the second column is the shape to play.
+    fn part1(rounds: &Vec<(char, char)>) -> i32 {
+        total_score(
+            rounds,
+            HashMap::from([
+                (('A', 'X'), 3 + 1),
+                (('A', 'Y'), 6 + 2),
+                (('A', 'Z'), 3),
+                (('B', 'X'), 1),
+                (('B', 'Y'), 3 + 2),
+                (('B', 'Z'), 6 + 3),
+                (('C', 'X'), 6 + 1),
+                (('C', 'Y'), 2),
+                (('C', 'Z'), 3 + 3),
+            ]),
+        )
+    }
+
+    /// Total score when the second column is the result to aim for (lose, draw, win).
+    fn part2(rounds: &Vec<(char, char)>) -> i32 {
+        total_score(
+            rounds,
+            HashMap::from([
+                (('A', 'X'), 3),
+                (('A', 'Y'), 3 + 1),
+                (('A', 'Z'), 6 + 2),
+                (('B', 'X'), 1),
+                (('B', 'Y'), 3 + 2),
+                (('B', 'Z'), 6 + 3),
+                (('C', 'X'), 2),
+                (('C', 'Y'), 3 + 3),
+                (('C', 'Z'), 6 + 1),
+            ]),
+        )
+    }
+}
@@ -0,0 +1,83 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+use std::collections::HashSet;
+
+pub struct Day;
+
+impl Solution for Day {
+    /// The contents of each rucksack.
+    type Input<'a> = Vec<&'a [u8]>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<&[u8]>, ParseError> {
+        parse::each_line(input, |line| {
+            let items = line.take_while(|c| c.is_ascii_alphabetic(), "an item type")?;
+            if items.len() % 2 != 0 {
+                return Err(line.error("an even number of items"));
+            }
+            Ok(items.as_bytes())
+        })
+    }
+
+    fn part1(rucksacks: &Vec<&[u8]>) -> i32 {
+        common(rucksacks)
+    }
+
+    fn part2(rucksacks: &Vec<&[u8]>) -> i32 {
+        badges(rucksacks)
+    }
+}
+
+/// Sum of the priorities of the item types common to both compartments of each rucksack.
+fn common(rucksacks: &[&[u8]]) -> i32 {
+    let mut total: i32 = 0;
+    for &bytes in rucksacks {
+        let half_way = bytes.len() / 2;
+        let mut first = HashSet::new();
+        for element in bytes[..half_way].iter() {
+            first.insert(element);
+        }
+        let mut second = HashSet::new();
+        for element in bytes[half_way..].iter() {
+            second.insert(element);
+        }
+        for &element in first.intersection(&second) {
+            total += priority(*element);
+        }
+    }
+    total
+}
+
+/// Sum of the priorities of the badge carried by each group of three elves.
+fn badges(rucksacks: &[&[u8]]) -> i32 {
+    let mut total: i32 = 0;
+    let mut group_bytes = HashSet::<u8>::new();
+    for (index, &line) in rucksacks.iter().enumerate() {
+        if index % 3 == 0 {
+            for &element in line {
+                group_bytes.insert(element);
+            }
+        } else {
+            let mut bytes = HashSet::<u8>::new();
+            for &element in line {
+                bytes.insert(element);
+            }
+            group_bytes.retain(|x| bytes.contains(x));
+        }
+        if index % 3 == 2 {
+            for &element in &group_bytes {
+                total += priority(element);
+            }
+            group_bytes.clear();
+        }
+    }
+    total
+}
+
+fn priority(element: u8) -> i32 {
+    if element >= 0x61 {
+        return i32::from(element - 0x60);
+    }
+    i32::from(element - 0x41 + 27)
+}
@@ -0,0 +1,61 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+enum Mode {
+    Contains,
+    Overlap,
+}
+
+type Range = (i32, i32);
+
+fn count(pairs: &[(Range, Range)], mode: Mode) -> i32 {
+    let mut count = 0;
+    for &(first, second) in pairs {
+        if test(&mode, first, second) {
+            count += 1;
+        }
+    }
+    count
+}
+
+impl Solution for Day {
+    /// The pair of section ranges assigned to each pair of elves.
+    type Input<'a> = Vec<(Range, Range)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<(Range, Range)>, ParseError> {
+        parse::each_line(input, |line| {
+            let first = range(line)?;
+            line.tag(",")?;
+            Ok((first, range(line)?))
+        })
+    }
+
+    /// Number of pairs where one range fully contains the other.
+    fn part1(pairs: &Vec<(Range, Range)>) -> i32 {
+        count(pairs, Mode::Contains)
+    }
+
+    /// Number of pairs where the ranges overlap at all.
+    fn part2(pairs: &Vec<(Range, Range)>) -> i32 {
+        count(pairs, Mode::Overlap)
+    }
+}
+
+fn range(line: &mut Line) -> Result<Range, ParseError> {
+    let start = line.number()?;
+    line.tag("-")?;
+    Ok((start, line.number()?))
+}
+
+fn test(mode: &Mode, first: Range, second: Range) -> bool {
+    let (a, b) = first;
+    let (c, d) = second;
+    match mode {
+        Mode::Contains => (a <= c && b >= d) || (c <= a && d >= b),
+        Mode::Overlap => !(b < c || a > d),
+    }
+}
@@ -0,0 +1,138 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+mod visualise;
+
+pub use visualise::visualise;
+
+pub struct Day;
+
+pub struct Move {
+    count: usize,
+    src: usize,
+    dst: usize,
+}
+
+/// The starting stacks of crates (bottom first), and the rearrangement procedure.
+pub struct Procedure {
+    stacks: Vec<Vec<u8>>,
+    moves: Vec<Move>,
+}
+
+impl Procedure {
+    /// Carries out the moves, showing `moved` the stacks after each, and returns the top crate
+    /// of each stack.
+    fn rearrange(&self, multi_move: bool, mut moved: impl FnMut(&[Vec<u8>])) -> String {
+        let mut stacks = self.stacks.clone();
+        for &Move { count, src, dst } in &self.moves {
+            let split_index = stacks[src].len() - count;
+            let mut crates = stacks[src].split_off(split_index);
+            if !multi_move {
+                crates.reverse();
+            }
+            stacks[dst].extend(crates);
+            moved(&stacks);
+        }
+
+        String::from_utf8(stacks.iter().map(|s| *s.last().unwrap()).collect()).unwrap()
+    }
+}
+
+impl Solution for Day {
+    type Input<'a> = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+    const SHAPE: Shape = Shape::Text;
+
+    fn parse(input: &str) -> Result<Procedure, ParseError> {
+        let blocks = parse::blocks(input);
+        let [drawing, moves] = &blocks[..] else {
+            let lines = input.lines().count();
+            return Err(ParseError::end_of_input(lines, "a drawing and a list of moves"));
+        };
+
+        // The last row of the drawing numbers the stacks.
+        let (&numbers, rows) = drawing.split_last().unwrap();
+        let mut numbers = numbers;
+        let mut count = 0;
+        loop {
+            numbers.spaces();
+            if numbers.at_end() {
+                break;
+            }
+            let pos = numbers;
+            if numbers.number::<usize>()? != count + 1 {
+                return Err(pos.error(format!("stack number {}", count + 1)));
+            }
+            count += 1;
+        }
+
+        let mut stacks = vec![Vec::<u8>::new(); count];
+        for row in rows.iter().rev() {
+            // Byte offsets are columns until the first character that isn't ASCII, which is an
+            // error anyway.
+            for (index, chr) in row.text().char_indices() {
+                if !chr.is_ascii() {
+                    return Err(row.error_at(index, "a drawing of crates"));
+                }
+                if index % 4 != 1 || chr == ' ' {
+                    continue;
+                }
+                if !chr.is_ascii_uppercase() || index / 4 >= count {
+                    return Err(row.error_at(index, "a crate within a numbered stack"));
+                }
+                stacks[index / 4].push(chr as u8);
+            }
+        }
+
+        let stack = |line: &mut Line| {
+            let pos = *line;
+            match line.number::<usize>()? {
+                n @ 1.. if n <= count => Ok(n - 1),
+                _ => Err(pos.error(format!("a stack number from 1 to {}", count))),
+            }
+        };
+        let moves = moves
+            .iter()
+            .map(|line| {
+                line.parse(|line| {
+                    line.tag("move ")?;
+                    let count = line.number()?;
+                    line.tag(" from ")?;
+                    let src = stack(line)?;
+                    line.tag(" to ")?;
+                    let dst = stack(line)?;
+                    Ok(Move { count, src, dst })
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    /// Top crate of each stack when the crane moves one crate at a time.
+    fn part1(procedure: &Procedure) -> String {
+        procedure.rearrange(false, |_| {})
+    }
+
+    /// Top crate of each stack when the crane moves several crates at once.
+    fn part2(procedure: &Procedure) -> String {
+        procedure.rearrange(true, |_| {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_parse_non_ascii() {
+        let input = "é   [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n";
+        assert_eq!(
+            "line 1, column 1: expected a drawing of crates, found 'é'",
+            Day::parse(input).err().unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Pictures of the crates being moved between stacks.
+
+use crate::Day;
+use aoc_core::parse::ParseError;
+use aoc_core::render::{Frame, Rgb};
+use aoc_core::Solution;
+
+/// Empty space, then a colour for each crate letter from A to Z.
+const PALETTE: [Rgb; 27] = palette();
+
+const fn palette() -> [Rgb; 27] {
+    let mut palette = [[12, 12, 16]; 27];
+    let mut i = 1;
+    while i < 27 {
+        let c = i as u32;
+        palette[i] = [
+            ((c * 97) % 200 + 55) as u8,
+            ((c * 57) % 200 + 55) as u8,
+            ((c * 151) % 200 + 55) as u8,
+        ];
+        i += 1;
+    }
+    palette
+}
+
+/// Draws the stacks at the start and after each move of the CrateMover 9000, with a gap between
+/// stacks and room above them for every crate to be piled on one.
+pub fn visualise(input: &str, frame: &mut dyn FnMut(&Frame)) -> Result<(), ParseError> {
+    let procedure = Day::parse(input)?;
+    let width = procedure.stacks.len() * 2 - 1;
+    let height = procedure.stacks.iter().map(Vec::len).sum::<usize>().max(1);
+    let mut draw = |stacks: &[Vec<u8>]| {
+        let mut f = Frame::new(width, height, &PALETTE);
+        for (i, stack) in stacks.iter().enumerate() {
+            for (j, &c) in stack.iter().enumerate() {
+                f.set(i * 2, height - 1 - j, c - b'A' + 1);
+            }
+        }
+        frame(&f);
+    };
+    draw(&procedure.stacks);
+    procedure.rearrange(false, draw);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::visualise;
+    use aoc_core::example;
+
+    #[test]
+    fn test_visualise() {
+        let mut frames = vec![];
+        visualise(&example!(), &mut |f| frames.push(f.clone())).unwrap();
+        // The start, and a frame for each of the four moves.
+        assert_eq!(5, frames.len());
+        let last = frames.last().unwrap();
+        assert_eq!((5, 6), (last.width(), last.height()));
+        // The stacks end up as C, M and PDNZ from the bottom.
+        assert_eq!(3, last.get(0, 5));
+        assert_eq!(13, last.get(2, 5));
+        assert_eq!([16, 4, 14, 26], [5, 4, 3, 2].map(|y| last.get(4, y)));
+        assert_eq!(0, last.get(4, 1));
+    }
+}
@@ -0,0 +1,75 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+/// Returns the number of characters processed before the first run of `n` distinct characters
+/// has been seen.
+fn find_marker(message: &str, n: usize) -> Option<usize> {
+    let message_bytes = message.as_bytes();
+
+    assert!(message.len() > n);
+
+    let mut chrs = [0; 256];
+    let mut n_dups = 0;
+    for &c in &message_bytes[..n] {
+        let c = c as usize;
+        if chrs[c] > 0 {
+            n_dups += 1;
+        }
+        chrs[c] += 1;
+    }
+
+    if n_dups == 0 {
+        return Some(n);
+    }
+
+    for i in n..message_bytes.len() {
+        let last = message_bytes[i] as usize;
+        if chrs[last] > 0 {
+            n_dups += 1;
+        }
+        chrs[last] += 1;
+
+        let first = message_bytes[i - n] as usize;
+        if chrs[first] > 1 {
+            n_dups -= 1;
+        }
+        chrs[first] -= 1;
+
+        if n_dups == 0 {
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+impl Solution for Day {
+    /// The datastream buffer.
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::SingleLine;
+
+    fn parse(input: &str) -> Result<&str, ParseError> {
+        parse::single_line(input, |line| {
+            let message = line.take_while(|c| c.is_ascii_lowercase(), "a letter")?;
+            if message.len() <= 14 {
+                return Err(line.error("a message longer than 14 characters"));
+            }
+            Ok(message)
+        })
+    }
+
+    /// Position of the start-of-packet marker.
+    fn part1(message: &&str) -> usize {
+        find_marker(message, 4).expect("Not found")
+    }
+
+    /// Position of the start-of-message marker.
+    fn part2(message: &&str) -> usize {
+        find_marker(message, 14).expect("Not found")
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+struct Folder {
+    parent: Option<usize>,
+    subfolders: Vec<usize>,
+    files: Vec<usize>,
+    size: Option<usize>,
+}
+
+struct File {
+    size: usize,
+}
+
+/// Replays the terminal session and returns the total size of every folder.
+fn folder_sizes(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut folders: Vec<Folder> = vec![];
+    let mut files: Vec<File> = vec![];
+
+    folders.push(Folder {
+        parent: None,
+        files: vec![],
+        subfolders: vec![],
+        size: None,
+    });
+
+    let mut pwd: usize = 0;
+
+    for mut line in parse::lines(input) {
+        if line.eat("$ ls") {
+            line.end()?;
+        } else if line.eat("dir ") {
+            line.take_rest("a folder name")?;
+        } else if line.eat("$ cd /") {
+            line.end()?;
+            pwd = 0;
+        } else if line.eat("$ cd ..") {
+            line.end()?;
+            pwd = match folders[pwd].parent {
+                Some(parent) => parent,
+                None => return Err(line.error_at(0, "a folder to leave")),
+            };
+        } else if line.eat("$ cd ") {
+            line.take_rest("a folder name")?;
+            let next = folders.len();
+            folders[pwd].subfolders.push(next);
+            folders.push(Folder {
+                parent: Some(pwd),
+                files: vec![],
+                subfolders: vec![],
+                size: None,
+            });
+            pwd = next;
+        } else {
+            let size = line.number::<u32>()? as usize;
+            line.tag(" ")?;
+            line.take_rest("a file name")?;
+            let next = files.len();
+            files.push(File { size });
+            folders[pwd].files.push(next);
+        }
+    }
+
+    for folder in (0..folders.len()).rev() {
+        folders[folder].size = Some(calc_folder_size(&folders, &files, folder));
+    }
+
+    Ok(folders.iter().map(|f| f.size.unwrap()).collect())
+}
+
+impl Solution for Day {
+    /// The total size of each folder, starting with the outermost.
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        folder_sizes(input)
+    }
+
+    /// Total size of all folders of at most 100000.
+    fn part1(sizes: &Vec<usize>) -> usize {
+        sizes.iter().filter(|&&s| s <= 100000).sum::<usize>()
+    }
+
+    /// Size of the smallest folder that frees up enough space for the update.
+    fn part2(sizes: &Vec<usize>) -> usize {
+        let outer_size = sizes[0];
+        let free = 70000000 - outer_size;
+        let to_free = 30000000 - free;
+        sizes.iter().copied().filter(|&s| s >= to_free).min().unwrap()
+    }
+}
+
+fn calc_folder_size(folders: &[Folder], files: &[File], index: usize) -> usize {
+    match folders[index].size {
+        Some(i) => i,
+        None => {
+            let mut size: usize = 0;
+            for &f in &folders[index].files {
+                size += files[f].size;
+            }
+            for &f in &folders[index].subfolders {
+                size += calc_folder_size(folders, files, f);
+            }
+            size
+        }
+    }
+}
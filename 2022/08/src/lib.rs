@@ -0,0 +1,91 @@
+use aoc_core::geom::Dir4;
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
+
+pub struct Day;
+
+fn count_visible(trees: &Grid<i8>) -> i32 {
+    let mut visible = Grid::filled(trees.width(), trees.height(), false);
+    let mut count = 0;
+    for (start, dir) in searches(trees) {
+        let mut max: i8 = -1;
+        let mut pos = Some(start);
+        while let Some(p) = pos {
+            if trees[p] > max {
+                max = trees[p];
+                if !visible[p] {
+                    count += 1;
+                    visible[p] = true;
+                }
+            }
+            pos = trees.step(p, dir);
+        }
+    }
+    count
+}
+
+/// The tree at each edge to look in from, and the direction to look.
+fn searches(trees: &Grid<i8>) -> Vec<(Pos, Dir4)> {
+    let (w, h) = (trees.width(), trees.height());
+    let mut ret = vec![];
+    for x in 0..w {
+        ret.push(((0, x), Dir4::South));
+        ret.push(((h - 1, x), Dir4::North));
+    }
+    for y in 0..h {
+        ret.push(((y, 0), Dir4::East));
+        ret.push(((y, w - 1), Dir4::West));
+    }
+    ret
+}
+
+fn best_scenic_score(trees: &Grid<i8>) -> i32 {
+    trees
+        .positions()
+        .map(|pos| {
+            Dir4::ALL
+                .iter()
+                .map(|&dir| count_scenic(trees, pos, dir))
+                .product()
+        })
+        .max()
+        .unwrap()
+}
+
+fn count_scenic(trees: &Grid<i8>, pos: Pos, dir: Dir4) -> i32 {
+    let max = trees[pos];
+    let mut count = 0;
+    let mut p = pos;
+    while let Some(next) = trees.step(p, dir) {
+        count += 1;
+        if trees[next] >= max {
+            break;
+        }
+        p = next;
+    }
+    count
+}
+
+impl Solution for Day {
+    /// The height of each tree.
+    type Input<'a> = Grid<i8>;
+    type Part1 = i32;
+    type Part2 = i32;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Grid<i8>, ParseError> {
+        Grid::parse(input, "0123456789", |c| (c as u8 - b'0') as i8)
+    }
+
+    /// Number of trees visible from outside the grid.
+    fn part1(trees: &Grid<i8>) -> i32 {
+        count_visible(trees)
+    }
+
+    /// Highest scenic score possible for any tree.
+    fn part2(trees: &Grid<i8>) -> i32 {
+        best_scenic_score(trees)
+    }
+}
@@ -0,0 +1,67 @@
+use aoc_core::geom::{Dir4, Point2};
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+use std::collections::HashSet;
+
+mod visualise;
+
+pub use visualise::visualise;
+
+pub struct Day;
+
+/// Simulates a rope of the given length and returns the number of positions visited by its tail.
+/// After each step of the head, `moved` is shown the knots and the positions the tail has visited.
+fn simulate(
+    motions: &[(Dir4, i32)],
+    length: usize,
+    mut moved: impl FnMut(&[Point2], &HashSet<Point2>),
+) -> usize {
+    let last = length - 1;
+
+    let mut rope = vec![Point2::ORIGIN; length];
+    let mut visited = HashSet::from([Point2::ORIGIN]);
+    let mut unique_visited_count = 1;
+
+    for &(dir, count) in motions {
+        for _ in 0..count {
+            rope[0] += dir;
+            for i in 0..last {
+                let (a, b) = (rope[i], rope[i + 1]);
+                if a.chebyshev(b) > 1 {
+                    rope[i + 1] = b + (a - b).signum();
+                }
+            }
+            if !visited.contains(&rope[last]) {
+                unique_visited_count += 1;
+                visited.insert(rope[last]);
+            }
+            moved(&rope, &visited);
+        }
+    }
+    unique_visited_count
+}
+
+impl Solution for Day {
+    /// The direction and distance of each motion of the head.
+    type Input<'a> = Vec<(Dir4, i32)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Dir4, i32)>, ParseError> {
+        parse::each_line(input, |line| {
+            let dir = Dir4::from_glyph(line.one_of("UDLR")?).unwrap();
+            line.tag(" ")?;
+            Ok((dir, line.number()?))
+        })
+    }
+
+    /// Positions visited by the tail of a two-knot rope.
+    fn part1(motions: &Vec<(Dir4, i32)>) -> usize {
+        simulate(motions, 2, |_, _| {})
+    }
+
+    /// Positions visited by the tail of a ten-knot rope.
+    fn part2(motions: &Vec<(Dir4, i32)>) -> usize {
+        simulate(motions, 10, |_, _| {})
+    }
+}
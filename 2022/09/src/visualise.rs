@@ -0,0 +1,60 @@
+//! Pictures of the ten-knot rope being dragged around.
+
+use crate::{simulate, Day};
+use aoc_core::geom::Point2;
+use aoc_core::parse::ParseError;
+use aoc_core::render::{Frame, Rgb};
+use aoc_core::Solution;
+
+/// Empty space, positions the tail has visited, the knots, and the head.
+const PALETTE: &[Rgb] = &[[10, 14, 10], [40, 90, 50], [220, 220, 220], [230, 60, 50]];
+
+/// Draws the rope of part 2 after each step of its head, on a grid big enough for everywhere it
+/// goes.
+pub fn visualise(input: &str, frame: &mut dyn FnMut(&Frame)) -> Result<(), ParseError> {
+    let motions = Day::parse(input)?;
+
+    // A first run finds how far the rope reaches.
+    let (mut x0, mut y0, mut x1, mut y1) = (0, 0, 0, 0);
+    simulate(&motions, 10, |rope, _| {
+        for knot in rope {
+            (x0, y0) = (x0.min(knot.x), y0.min(knot.y));
+            (x1, y1) = (x1.max(knot.x), y1.max(knot.y));
+        }
+    });
+
+    let (width, height) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+    let cell = |knot: &Point2| ((knot.x - x0) as usize, (knot.y - y0) as usize);
+    simulate(&motions, 10, |rope, visited| {
+        let mut f = Frame::new(width, height, PALETTE);
+        for v in visited {
+            let (x, y) = cell(v);
+            f.set(x, y, 1);
+        }
+        for (i, knot) in rope.iter().enumerate().rev() {
+            let (x, y) = cell(knot);
+            f.set(x, y, if i == 0 { 3 } else { 2 });
+        }
+        frame(&f);
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::visualise;
+    use aoc_core::example;
+
+    #[test]
+    fn test_visualise() {
+        let mut frames = vec![];
+        visualise(&example!(), &mut |f| frames.push(f.clone())).unwrap();
+        // A frame for each step of the head.
+        assert_eq!(24, frames.len());
+        let last = frames.last().unwrap();
+        assert_eq!((6, 5), (last.width(), last.height()));
+        // The head ends up two above the start, and the tail never leaves the bottom left.
+        assert_eq!(3, last.get(2, 2));
+        assert_eq!(2, last.get(0, 4));
+    }
+}
@@ -0,0 +1,123 @@
+//! The CPU and CRT a cycle at a time, for the step debugger.
+
+use crate::{render, Day};
+use aoc_core::debug::{State, Value};
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
+
+/// The machine during one cycle.
+struct Cpu<'a> {
+    cycle: i64,
+    x: i32,
+    /// The index of the instruction running.
+    index: usize,
+    instruction: (i32, i32),
+    /// The CRT's image so far, including the pixel drawn this cycle.
+    image: &'a str,
+}
+
+impl Cpu<'_> {
+    fn instruction(&self) -> String {
+        match self.instruction {
+            (1, _) => "noop".to_string(),
+            (_, dx) => format!("addx {}", dx),
+        }
+    }
+
+    fn pixel(&self) -> &str {
+        let image = self.image.trim_end();
+        &image[image.len() - 1..]
+    }
+}
+
+impl State for Cpu<'_> {
+    fn unit(&self) -> &'static str {
+        "cycle"
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "cycle {}: X = {} during `{}` (line {}), signal strength {}, drew {}",
+            self.cycle,
+            self.x,
+            self.instruction(),
+            self.index + 1,
+            self.cycle * self.x as i64,
+            self.pixel()
+        )
+    }
+
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("cycle", self.cycle.into()),
+            ("x", (self.x as i64).into()),
+            ("signal", (self.cycle * self.x as i64).into()),
+            ("line", (self.index as i64 + 1).into()),
+            ("op", self.instruction()[..4].into()),
+            ("pixel", self.pixel().into()),
+        ]
+    }
+
+    fn inspect(&self) -> String {
+        format!(
+            "cycle {}, X = {} (sprite over {} to {})\nline {}: {}\nCRT:\n{}",
+            self.cycle,
+            self.x,
+            self.x - 1,
+            self.x + 1,
+            self.index + 1,
+            self.instruction(),
+            self.image.trim_end()
+        )
+    }
+}
+
+/// Runs the program, stopping after each cycle. Both parts run the same machine.
+pub fn debug(
+    input: &str,
+    _part: u8,
+    stepped: &mut dyn FnMut(&dyn State) -> bool,
+) -> Result<(), ParseError> {
+    let instructions = Day::parse(input)?;
+    let (mut cycle, mut running) = (0, true);
+    render(&instructions, |image, x, index| {
+        cycle += 1;
+        if running {
+            let instruction = instructions[index];
+            running = stepped(&Cpu {
+                cycle,
+                x,
+                index,
+                instruction,
+                image,
+            });
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::debug;
+    use aoc_core::debug::Value;
+    use aoc_core::example;
+
+    #[test]
+    fn test_debug() {
+        let mut states = vec![];
+        debug(&example!(), 1, &mut |s| {
+            states.push((s.describe(), s.fields()));
+            s.fields()[0].1 != Value::Int(20)
+        })
+        .unwrap();
+        // Stops when asked, during the 20th cycle, when X is 21.
+        assert_eq!(20, states.len());
+        let (describe, fields) = states.pop().unwrap();
+        assert_eq!(
+            "cycle 20: X = 21 during `addx -1` (line 11), signal strength 420, drew .",
+            describe
+        );
+        assert_eq!(("x", Value::Int(21)), fields[1]);
+        assert_eq!(("op", Value::from("addx")), fields[4]);
+    }
+}
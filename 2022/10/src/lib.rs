@@ -0,0 +1,82 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+mod debug;
+mod visualise;
+
+pub use debug::debug;
+pub use visualise::visualise;
+
+pub struct Day;
+
+impl Solution for Day {
+    /// The number of cycles taken by each instruction, and the change it makes to X.
+    type Input<'a> = Vec<(i32, i32)>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+        instructions(input)
+    }
+
+    fn part1(instructions: &Vec<(i32, i32)>) -> i32 {
+        total(instructions)
+    }
+
+    fn part2(instructions: &Vec<(i32, i32)>) -> String {
+        render(instructions, |_, _, _| {})
+    }
+}
+
+/// Sum of the signal strengths during the 20th, 60th, 100th, ... cycles.
+fn total(instructions: &[(i32, i32)]) -> i32 {
+    let mut total = 0;
+    let mut counter = 0;
+    let mut next = 20;
+    let mut x = 1;
+    for &(n, dx) in instructions {
+        counter += n;
+        if counter >= next {
+            total += next * x;
+            next += 40;
+        }
+        x += dx;
+    }
+    total
+}
+
+/// The image drawn on the CRT, one row of 40 pixels per line. After each pixel is drawn, `drawn`
+/// is shown the image so far, where the sprite is, and the index of the instruction running.
+fn render(instructions: &[(i32, i32)], mut drawn: impl FnMut(&str, i32, usize)) -> String {
+    let mut out = String::new();
+    let mut counter: i32 = 0;
+    let mut x = 1;
+    for (i, &(n, dx)) in instructions.iter().enumerate() {
+        for _ in 0..n {
+            if (x - (counter % 40)).abs() <= 1 {
+                out.push('#');
+            } else {
+                out.push('.');
+            }
+            counter += 1;
+            if counter % 40 == 0 {
+                out.push('\n');
+            }
+            drawn(&out, x, i);
+        }
+        x += dx;
+    }
+    out
+}
+
+fn instructions(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    parse::each_line(input, |line| {
+        if line.eat("noop") {
+            Ok((1, 0))
+        } else if line.eat("addx ") {
+            Ok((2, line.number()?))
+        } else {
+            Err(line.error("\"noop\" or \"addx\""))
+        }
+    })
+}
@@ -0,0 +1,51 @@
+//! Pictures of the CRT drawing its image.
+
+use crate::{render, Day};
+use aoc_core::parse::ParseError;
+use aoc_core::render::{Frame, Rgb};
+use aoc_core::Solution;
+
+/// Pixels not drawn yet, dark and lit pixels, the sprite, and the pixel being drawn next.
+const PALETTE: &[Rgb] = &[[0, 0, 0], [30, 40, 30], [120, 255, 120], [60, 110, 60], [255, 255, 255]];
+
+/// Draws the screen after each cycle, with the sprite on the row being drawn, until it's full.
+pub fn visualise(input: &str, frame: &mut dyn FnMut(&Frame)) -> Result<(), ParseError> {
+    let instructions = Day::parse(input)?;
+    render(&instructions, |image, sprite, _| {
+        let mut f = Frame::new(40, 6, PALETTE);
+        let pixels = image.bytes().filter(|&b| b != b'\n').take(240);
+        let mut count = 0;
+        for (i, pixel) in pixels.enumerate() {
+            f.set(i % 40, i / 40, if pixel == b'#' { 2 } else { 1 });
+            count += 1;
+        }
+        if count < 240 {
+            let (x, y) = (count % 40, count / 40);
+            for sx in sprite - 1..=sprite + 1 {
+                if (x as i32..40).contains(&sx) {
+                    f.set(sx as usize, y, 3);
+                }
+            }
+            f.set(x, y, 4);
+        }
+        frame(&f);
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::visualise;
+    use aoc_core::example;
+
+    #[test]
+    fn test_visualise() {
+        let mut frames = vec![];
+        visualise(&example!(), &mut |f| frames.push(f.clone())).unwrap();
+        assert_eq!(240, frames.len());
+        // The example's image starts `##..##..`.
+        let last = frames.last().unwrap();
+        assert_eq!([2, 2, 1, 1, 2, 2, 1, 1], [0, 1, 2, 3, 4, 5, 6, 7].map(|x| last.get(x, 0)));
+        assert_eq!(4, frames[0].get(1, 0));
+    }
+}
@@ -0,0 +1,112 @@
+//! The monkeys' rounds, one inspection at a time, for the step debugger.
+
+use crate::{monkey_business, Day, Inspection, Monkey};
+use aoc_core::debug::{State, Value};
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
+use std::fmt::Write;
+
+/// The monkeys just after one of them has inspected an item and thrown it.
+struct Troop<'a> {
+    inspection: &'a Inspection,
+    monkeys: &'a [Monkey],
+    /// The items the inspecting monkey still holds.
+    rest: &'a [usize],
+}
+
+impl State for Troop<'_> {
+    fn unit(&self) -> &'static str {
+        "round"
+    }
+
+    fn describe(&self) -> String {
+        let i = self.inspection;
+        format!(
+            "round {}: monkey {} inspects an item with worry level {}, which becomes {}, and \
+             throws it to monkey {}",
+            i.round, i.monkey, i.old, i.new, i.to
+        )
+    }
+
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        let i = self.inspection;
+        vec![
+            ("round", (i.round as i64).into()),
+            ("monkey", (i.monkey as i64).into()),
+            ("item", (i.old as i64).into()),
+            ("worry", (i.new as i64).into()),
+            ("to", (i.to as i64).into()),
+        ]
+    }
+
+    fn inspect(&self) -> String {
+        let mut out = format!("{}\n", self.describe());
+        for (n, monkey) in self.monkeys.iter().enumerate() {
+            let items = match n == self.inspection.monkey {
+                true => self.rest,
+                false => &monkey.items[..],
+            };
+            let items: Vec<_> = items.iter().map(|i| i.to_string()).collect();
+            let items = match items.is_empty() {
+                true => "nothing".to_string(),
+                false => items.join(", "),
+            };
+            let inspections = monkey.num_inspections;
+            let _ = writeln!(out, "monkey {} ({} inspections) holds {}", n, inspections, items);
+        }
+        out.trim_end().to_string()
+    }
+}
+
+/// Runs the monkeys for as many rounds as the part does (20, or 10,000 without relief for part 2),
+/// stopping after each inspection.
+pub fn debug(
+    input: &str,
+    part: u8,
+    stepped: &mut dyn FnMut(&dyn State) -> bool,
+) -> Result<(), ParseError> {
+    let monkeys = Day::parse(input)?;
+    let (rounds, long) = if part == 1 { (20, false) } else { (10_000, true) };
+    let mut running = true;
+    monkey_business(&monkeys, rounds, long, |inspection, monkeys, rest| {
+        if running {
+            running = stepped(&Troop {
+                inspection,
+                monkeys,
+                rest,
+            });
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::debug;
+    use aoc_core::debug::Value;
+    use aoc_core::example;
+
+    #[test]
+    fn test_debug() {
+        let mut steps = 0;
+        debug(&example!(), 1, &mut |s| {
+            steps += 1;
+            if steps == 1 {
+                // Monkey 0 inspects 79, which becomes 79 * 19 / 3 = 500.
+                assert_eq!(("worry", Value::Int(500)), s.fields()[3]);
+                assert_eq!(
+                    "round 1: monkey 0 inspects an item with worry level 79, which becomes 500, \
+                     and throws it to monkey 3\n\
+                     monkey 0 (1 inspections) holds 98\n\
+                     monkey 1 (0 inspections) holds 54, 65, 75, 74\n\
+                     monkey 2 (0 inspections) holds 79, 60, 97\n\
+                     monkey 3 (0 inspections) holds 74, 500",
+                    s.inspect()
+                );
+            }
+            steps < 5
+        })
+        .unwrap();
+        assert_eq!(5, steps);
+    }
+}
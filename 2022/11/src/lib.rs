@@ -0,0 +1,193 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+mod debug;
+
+pub use debug::debug;
+
+pub struct Day;
+
+#[derive(Clone)]
+enum Operation {
+    Add,
+    Mul,
+    Square,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    operation: Operation,
+    operand: usize,
+    test_divisible_by: usize,
+    if_true: usize,
+    if_false: usize,
+    num_inspections: usize,
+}
+
+/// One monkey inspecting one item and throwing it on.
+struct Inspection {
+    /// Counting from 1.
+    round: usize,
+    monkey: usize,
+    /// The item's worry level before and after the inspection.
+    old: usize,
+    new: usize,
+    to: usize,
+}
+
+/// Runs the monkeys for the given number of rounds and returns the level of monkey business.
+/// After each inspection, `inspected` is shown it and the monkeys, which hold every item but those
+/// still waiting for the inspecting monkey (`rest`).
+///
+/// When `long` is set, worry levels are no longer divided by three after each inspection.
+fn monkey_business(
+    monkeys: &[Monkey],
+    num_rounds: usize,
+    long: bool,
+    mut inspected: impl FnMut(&Inspection, &[Monkey], &[usize]),
+) -> usize {
+    let mut monkeys = monkeys.to_vec();
+
+    // The product of all of the test_divisible_by values. Used to avoid worry
+    // levels overflowing, while still maintaining the ability to test divisibility.
+    let test_product = monkeys
+        .iter()
+        .map(|m| m.test_divisible_by)
+        .reduce(|a, e| a * e)
+        .unwrap();
+
+    for round in 1..=num_rounds {
+        for m in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[m].items);
+            for (i, &old) in items.iter().enumerate() {
+                let mut worry = match monkeys[m].operation {
+                    Operation::Add => old + monkeys[m].operand,
+                    Operation::Mul => old * monkeys[m].operand,
+                    Operation::Square => old * old,
+                };
+                worry = if long {
+                    worry % test_product
+                } else {
+                    worry / 3
+                };
+                let next = if worry.is_multiple_of(monkeys[m].test_divisible_by) {
+                    monkeys[m].if_true
+                } else {
+                    monkeys[m].if_false
+                };
+                monkeys[next].items.push(worry);
+                monkeys[m].num_inspections += 1;
+                let inspection = Inspection {
+                    round,
+                    monkey: m,
+                    old,
+                    new: worry,
+                    to: next,
+                };
+                inspected(&inspection, &monkeys, &items[i + 1..]);
+            }
+        }
+    }
+
+    let mut inspection_counts: Vec<usize> = monkeys.iter().map(|m| m.num_inspections).collect();
+    inspection_counts.sort();
+    inspection_counts.reverse();
+    inspection_counts[0] * inspection_counts[1]
+}
+
+impl Solution for Day {
+    type Input<'a> = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::Blocks;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    /// Monkey business after 20 rounds.
+    fn part1(monkeys: &Vec<Monkey>) -> usize {
+        monkey_business(monkeys, 20, false, |_, _, _| {})
+    }
+
+    /// Monkey business after 10,000 rounds without worry relief.
+    fn part2(monkeys: &Vec<Monkey>) -> usize {
+        monkey_business(monkeys, 10_000, true, |_, _, _| {})
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys = Vec::new();
+    for block in parse::blocks(input) {
+        let monkey = parse_monkey(&block, monkeys.len())?;
+        monkeys.push(monkey);
+    }
+    if monkeys.is_empty() {
+        return Err(ParseError::end_of_input(0, "a monkey"));
+    }
+    Ok(monkeys)
+}
+
+fn parse_monkey(block: &[Line], id: usize) -> Result<Monkey, ParseError> {
+    let end = block.last().unwrap().line_number();
+    let mut lines = block.iter().copied();
+    let mut next = |expected: &str| {
+        lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(end, expected))
+    };
+
+    next("a monkey")?.parse(|line| {
+        line.tag("Monkey ")?;
+        let start = *line;
+        if line.number::<usize>()? != id {
+            return Err(start.error(format!("monkey {}", id)));
+        }
+        line.tag(":")
+    })?;
+    let items = next("its starting items")?.parse(|line| {
+        line.tag("  Starting items:")?;
+        if line.at_end() {
+            return Ok(vec![]);
+        }
+        line.tag(" ")?;
+        line.list(", ", |l| l.number())
+    })?;
+    let (operation, operand) = next("its operation")?.parse(|line| {
+        line.tag("  Operation: new = old ")?;
+        let op = line.one_of("+*")?;
+        line.tag(" ")?;
+        match op {
+            '*' if line.eat("old") => Ok((Operation::Square, 0)),
+            '*' => Ok((Operation::Mul, line.number()?)),
+            _ => Ok((Operation::Add, line.number()?)),
+        }
+    })?;
+    let test_divisible_by = next("its test")?.parse(|line| {
+        line.tag("  Test: divisible by ")?;
+        line.number()
+    })?;
+    let if_true = next("its target if true")?.parse(|line| {
+        line.tag("    If true: throw to monkey ")?;
+        line.number()
+    })?;
+    let if_false = next("its target if false")?.parse(|line| {
+        line.tag("    If false: throw to monkey ")?;
+        line.number()
+    })?;
+    if let Some(extra) = lines.next() {
+        return Err(extra.error("a blank line"));
+    }
+
+    Ok(Monkey {
+        items,
+        operation,
+        operand,
+        test_divisible_by,
+        if_true,
+        if_false,
+        num_inspections: 0,
+    })
+}
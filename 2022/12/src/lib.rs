@@ -0,0 +1,58 @@
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::{search, Solution};
+
+pub struct Day;
+
+/// Fewest steps from any of the starting squares to the best signal location.
+fn shortest_path(grid: &Grid<u8>, part2: bool) -> i32 {
+    let starts = if part2 { part2_starts(grid) } else { part1_starts(grid) };
+    let climbable = |&pos: &Pos| {
+        let height = elevation(grid[pos]);
+        grid.neighbours4(pos).filter(move |&next| elevation(grid[next]) <= height + 1)
+    };
+    let explored = search::bfs(starts, climbable, |&pos| grid[pos] == b'E', |_| {});
+    explored.goal_cost().expect("No path found") as i32
+}
+
+fn part1_starts(grid: &Grid<u8>) -> Vec<Pos> {
+    grid.find(&b'S').into_iter().collect()
+}
+
+fn part2_starts(grid: &Grid<u8>) -> Vec<Pos> {
+    grid.iter().filter(|(_, &v)| elevation(v) == b'a').map(|(p, _)| p).collect()
+}
+
+fn elevation(v: u8) -> u8 {
+    if v == b'S' { b'a' } else if v == b'E' { b'z' } else { v }
+}
+
+impl Solution for Day {
+    /// The heightmap.
+    type Input<'a> = Grid<u8>;
+    type Part1 = i32;
+    type Part2 = i32;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        let grid = Grid::parse(input, "abcdefghijklmnopqrstuvwxyzSE", |c| c as u8)?;
+        for marker in [b'S', b'E'] {
+            if grid.find(&marker).is_none() {
+                let expected = format!("a square marked {:?}", marker as char);
+                return Err(ParseError::end_of_input(grid.height(), expected));
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Fewest steps from the start position to the best signal location.
+    fn part1(grid: &Grid<u8>) -> i32 {
+        shortest_path(grid, false)
+    }
+
+    /// Fewest steps from any square at elevation `a` to the best signal location.
+    fn part2(grid: &Grid<u8>) -> i32 {
+        shortest_path(grid, true)
+    }
+}
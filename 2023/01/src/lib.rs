@@ -0,0 +1,94 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+const LOOKUP: &[(&str, i64)] = &[
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+fn find_value(linestr: &str, n: usize) -> Option<i64> {
+    let s = linestr.get(n..).unwrap();
+    for (m, d) in LOOKUP {
+        if s.starts_with(m) {
+            return Some(*d);
+        }
+    }
+    None
+}
+
+impl Solution for Day {
+    /// The lines of the calibration document.
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse::each_line(input, |line| {
+            line.take_while(
+                |c| c.is_ascii_lowercase() || c.is_ascii_digit(),
+                "a letter or digit",
+            )
+        })
+    }
+
+    fn part1(lines: &Vec<&str>) -> i64 {
+        part_1(lines)
+    }
+
+    fn part2(lines: &Vec<&str>) -> i64 {
+        part_2(lines)
+    }
+}
+
+/// Sum of the calibration values made from the first and last digit on each line.
+fn part_1(lines: &[&str]) -> i64 {
+    let mut part_1_total: i64 = 0;
+    for linestr in lines {
+        if let Some(c) = linestr.chars().find(|c| c.is_ascii_digit()) {
+            part_1_total += i64::from(c.to_digit(10).unwrap()) * 10;
+        }
+        if let Some(c) = linestr.chars().rfind(|c| c.is_ascii_digit()) {
+            part_1_total += i64::from(c.to_digit(10).unwrap());
+        }
+    }
+    part_1_total
+}
+
+/// Sum of the calibration values when digits may also be spelled out.
+fn part_2(lines: &[&str]) -> i64 {
+    let mut part_2_total: i64 = 0;
+    for &linestr in lines {
+        for i in 0..linestr.len() {
+            if let Some(v) = find_value(linestr, i) {
+                part_2_total += v * 10;
+                break;
+            }
+        }
+        for i in 0..linestr.len() {
+            if let Some(v) = find_value(linestr, linestr.len() - 1 - i) {
+                part_2_total += v;
+                break;
+            }
+        }
+    }
+    part_2_total
+}
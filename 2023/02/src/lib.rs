@@ -1,19 +1,22 @@
 //! Solution to Advent of Code 2023 - Day 2.
 //!
 
-use std::io;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
 
 const MAX_RED: u32 = 12;
 const MAX_GREEN: u32 = 13;
 const MAX_BLUE: u32 = 14;
 
-/// ```
+/// ```text
 /// "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
 ///       |  \_____,_____/  \__________,_________/  \__,__/
 ///   index        hand                hand            hand
 /// ```
 #[derive(Debug, PartialEq)]
-struct Game {
+pub struct Game {
     index: u32,
     hands: Vec<Hand>,
 }
@@ -32,23 +35,17 @@ impl Game {
             * self.hands.iter().map(|h| h.blue).max().unwrap_or(0)
     }
 
-    fn parse(s: &str) -> Game {
-        let (l, r) = s.split_once(": ").expect("Invalid game string");
-        let index = l
-            .get(5..)
-            .expect("Invalid game string")
-            .parse::<u32>()
-            .expect("Invalid game ID");
-        let hands = r
-            .split(";")
-            .map(|s| Hand::parse(s.trim()))
-            .collect::<Vec<_>>();
-        Game { index, hands }
+    fn parse(line: &mut Line) -> Result<Game, ParseError> {
+        line.tag("Game ")?;
+        let index = line.number()?;
+        line.tag(": ")?;
+        let hands = line.list("; ", Hand::parse)?;
+        Ok(Game { index, hands })
     }
 }
 
 #[derive(Debug, PartialEq)]
-struct Hand {
+pub struct Hand {
     red: u32,
     green: u32,
     blue: u32,
@@ -63,68 +60,75 @@ impl Hand {
     /// Parses a Hand from its string representation.
     ///
     /// Examples:
+    /// ```text
+    /// "1 red, 2 green, 3 blue" => Hand { red: 1, green: 2, blue: 3}
+    /// "1 red" => Hand { red: 1, green: 0, blue: 0 }
     /// ```
-    /// parse("1 red, 2 green, 3 blue"); // => Hand { red: 1, green: 2, blue: 3}
-    /// parse("1 red"); // => Hand { red: 1, green: 0, blue: 0 }
-    /// ```
-    fn parse(s: &str) -> Hand {
+    fn parse(line: &mut Line) -> Result<Hand, ParseError> {
         let mut red: u32 = 0;
         let mut green: u32 = 0;
         let mut blue: u32 = 0;
-        for (a, b) in s
-            .split(",")
-            .map(|s| s.trim().split_once(" ").expect("Invalid colour"))
-        {
-            let i = a.parse::<u32>().expect("Invalid count");
-            match b {
+        line.list(", ", |line| {
+            let i = line.number::<u32>()?;
+            line.tag(" ")?;
+            let start = *line;
+            match line.word()? {
                 "red" => red = i,
                 "green" => green = i,
                 "blue" => blue = i,
-                _ => panic!("Invalid colour"),
+                _ => return Err(start.error("red, green or blue")),
             }
-        }
-        Hand { red, green, blue }
+            Ok(())
+        })?;
+        Ok(Hand { red, green, blue })
     }
 }
 
-fn main() {
-    let games = io::stdin()
-        .lines()
-        .map(|line| Game::parse(&line.unwrap()))
-        .collect::<Vec<_>>();
-
-    let part_1_sum = games
-        .iter()
-        .filter(|g| g.is_valid())
-        .map(|g| g.index)
-        .sum::<u32>();
-    println!("Part 1 sum: {}", part_1_sum);
-
-    let part_2_sum = games.iter().map(|g| g.min_power()).sum::<u32>();
-    println!("Part 2 sum: {}", part_2_sum);
+impl Solution for Day {
+    type Input<'a> = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse::each_line(input, Game::parse)
+    }
+
+    /// Sum of the IDs of the games that are possible with the bag's contents.
+    fn part1(games: &Vec<Game>) -> u32 {
+        games
+            .iter()
+            .filter(|g| g.is_valid())
+            .map(|g| g.index)
+            .sum::<u32>()
+    }
+
+    /// Sum of the powers of the minimum set of cubes for each game.
+    fn part2(games: &Vec<Game>) -> u32 {
+        games.iter().map(|g| g.min_power()).sum::<u32>()
+    }
 }
 
 #[test]
 fn parse_hand() {
     assert_eq!(
-        Hand::parse("1 red, 2 green, 3 blue"),
-        Hand {
+        Hand::parse(&mut Line::new(1, "1 red, 2 green, 3 blue")),
+        Ok(Hand {
             red: 1,
             green: 2,
             blue: 3
-        }
+        })
     );
 }
 
 #[test]
 fn parse_partial_hand() {
     assert_eq!(
-        Hand::parse("1 red"),
-        Hand {
+        Hand::parse(&mut Line::new(1, "1 red")),
+        Ok(Hand {
             red: 1,
             green: 0,
             blue: 0
-        }
+        })
     );
 }
 
@@ -151,15 +155,25 @@ fn hand_is_invalid() {
 #[test]
 fn parse_game() {
     assert_eq!(
-        Game::parse("Game 42: 1 red; 2 green; 3 blue"),
-        Game {
+        Game::parse(&mut Line::new(1, "Game 42: 1 red; 2 green; 3 blue")),
+        Ok(Game {
             index: 42,
             hands: vec! {
                 Hand { red: 1, green: 0, blue: 0 },
                 Hand { red: 0, green: 2, blue: 0 },
                 Hand{ red: 0, green: 0, blue: 3 },
             },
-        }
+        })
+    );
+}
+
+#[test]
+fn parse_bad_colour() {
+    assert_eq!(
+        "line 1, column 19: expected red, green or blue, found 'p'",
+        Hand::parse(&mut Line::new(1, "1 red, 2 green, 3 purple"))
+            .unwrap_err()
+            .to_string()
     );
 }
 
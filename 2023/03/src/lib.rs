@@ -1,19 +1,21 @@
+use aoc_core::grid::Grid;
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
 use std::collections::HashMap;
-use std::io;
 
-fn main() {
-    let buf: Vec<Vec<u8>> = io::stdin()
-        .lines()
-        .map(|line| line.unwrap().into_bytes())
-        .collect();
-    let width = buf[0].len();
+pub struct Day;
 
+/// Digits, empty space, and every symbol that might appear in the schematic.
+const CELLS: &str = ".0123456789!\"#$%&'()*+,-/:;<=>?@[\\]^_`{|}~";
+
+/// Returns the sum of all part numbers, and the sum of all gear ratios.
+fn scan(schematic: &Grid<u8>) -> (i32, i32) {
     let mut sum_of_part_numbers: i32 = 0;
     let mut gears: HashMap<(usize, usize), Vec<i32>> = HashMap::new();
 
-    for y in 0..buf.len() {
-        let el = &buf[y];
-        debug_assert_eq!(width, el.len());
+    for y in 0..schematic.height() {
+        let el = schematic.row(y);
         let mut x = 0;
         while x < el.len() {
             if el[x].is_ascii_digit() {
@@ -26,14 +28,14 @@ fn main() {
                 }
                 // Scan the bounding box around the number to see if it contains any symbols.
                 let y1 = if y > 0 { y - 1 } else { y };
-                let y2 = buf.len().min(y + 2);
+                let y2 = schematic.height().min(y + 2);
                 let x2 = el.len().min(x + 1);
                 'outer: for v in y1..y2 {
                     for u in x1..x2 {
-                        let sym = buf[v][u];
+                        let sym = schematic[(v, u)];
                         if sym != b'.' && !sym.is_ascii_digit() {
                             if sym == b'*' {
-                                gears.entry((u, v)).or_insert(Vec::new()).push(num);
+                                gears.entry((u, v)).or_default().push(num);
                             }
                             sum_of_part_numbers += num;
                             break 'outer;
@@ -50,6 +52,27 @@ fn main() {
         .filter(|g| g.len() == 2)
         .fold(0, |s, g| s + g[0] * g[1]);
 
-    println!("Sum of part numbers: {}", sum_of_part_numbers);
-    println!("Sum of gear ratios: {}", sum_of_gear_ratios);
+    (sum_of_part_numbers, sum_of_gear_ratios)
+}
+
+impl Solution for Day {
+    /// The engine schematic.
+    type Input<'a> = Grid<u8>;
+    type Part1 = i32;
+    type Part2 = i32;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(input, CELLS, |c| c as u8)
+    }
+
+    /// Sum of the numbers adjacent to a symbol.
+    fn part1(schematic: &Grid<u8>) -> i32 {
+        scan(schematic).0
+    }
+
+    /// Sum of the gear ratios of every `*` adjacent to exactly two numbers.
+    fn part2(schematic: &Grid<u8>) -> i32 {
+        scan(schematic).1
+    }
 }
@@ -0,0 +1,94 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use std::collections::HashSet;
+
+pub struct Day;
+
+/// Parses space-separated numbers up to the next `|` or the end of the line.
+fn parse_set(line: &mut Line) -> Result<HashSet<usize>, ParseError> {
+    let mut set = HashSet::new();
+    line.spaces();
+    while !line.at_end() && !line.rest().starts_with('|') {
+        set.insert(line.number()?);
+        line.spaces();
+    }
+    Ok(set)
+}
+
+fn match_count(line: &mut Line) -> Result<usize, ParseError> {
+    line.tag("Card")?;
+    line.spaces();
+    line.number::<usize>()?;
+    line.tag(":")?;
+    let ls = parse_set(line)?;
+    line.tag("|")?;
+    let rs = parse_set(line)?;
+    Ok(ls.intersection(&rs).count())
+}
+
+fn part_1(scores: &[usize]) -> usize {
+    scores.iter().filter(|s| **s > 0).map(|s| 2_usize.pow((*s as u32)-1)).sum::<usize>()
+}
+
+fn part_2(scores: &[usize]) -> usize {
+    let mut card_counts = vec![1; scores.len()];
+
+    for i in 0..card_counts.len() {
+        let i1 = i + 1;
+        let i2 = (i1 + scores[i]).min(scores.len());
+        for j in i1..i2 {
+            card_counts[j] += card_counts[i];
+        }
+    }
+
+    card_counts.iter().sum::<usize>()
+}
+
+impl Solution for Day {
+    /// The number of winning numbers on each card.
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse::each_line(input, match_count)
+    }
+
+    /// Total points won by the scratchcards.
+    fn part1(scores: &Vec<usize>) -> usize {
+        part_1(scores)
+    }
+
+    /// Total number of scratchcards held once all copies have been won.
+    fn part2(scores: &Vec<usize>) -> usize {
+        part_2(scores)
+    }
+}
+
+#[test]
+fn test_parse_set() {
+    assert_eq!(
+        Ok(HashSet::from([83, 86, 6, 31, 17, 9, 48, 53])),
+        parse_set(&mut Line::new(1, "83 86  6 31 17  9 48 53"))
+    );
+}
+
+#[test]
+fn test_match_count() {
+    assert_eq!(
+        Ok(4),
+        match_count(&mut Line::new(1, "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"))
+    );
+
+    assert_eq!(
+        Ok(0),
+        match_count(&mut Line::new(1, "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36"))
+    );
+
+    assert_eq!(
+        "line 1, column 13: expected \"|\", found end of line",
+        match_count(&mut Line::new(1, "Card 5: 87 8"))
+            .unwrap_err()
+            .to_string()
+    );
+}
@@ -0,0 +1,90 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+mod part1;
+mod part2;
+
+pub struct Day;
+
+/// The seeds, and the maps to apply to them in order. Each map is a list of
+/// `[destination, source, length]` ranges.
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<[u64; 3]>>,
+}
+
+fn parse_seeds(line: &mut Line) -> Result<Vec<u64>, ParseError> {
+    line.tag("seeds: ")?;
+    line.list(" ", |l| l.number())
+}
+
+fn parse_map(block: &[Line]) -> Result<Vec<[u64; 3]>, ParseError> {
+    block[0].parse(|l| {
+        l.word()?;
+        l.tag("-to-")?;
+        l.word()?;
+        l.tag(" map:")
+    })?;
+    block[1..]
+        .iter()
+        .map(|line| {
+            line.parse(|l| {
+                let dest = l.number()?;
+                l.tag(" ")?;
+                let source = l.number()?;
+                l.tag(" ")?;
+                Ok([dest, source, l.number()?])
+            })
+        })
+        .collect()
+}
+
+impl Solution for Day {
+    type Input<'a> = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+    const SHAPE: Shape = Shape::Blocks;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        let blocks = parse::blocks(input);
+        let Some((first, maps)) = blocks.split_first() else {
+            return Err(ParseError::end_of_input(0, "\"seeds: \""));
+        };
+        if let Some(extra) = first.get(1) {
+            return Err(extra.error("a blank line"));
+        }
+        Ok(Almanac {
+            seeds: first[0].parse(parse_seeds)?,
+            maps: maps.iter().map(|m| parse_map(m)).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Lowest location number for any of the initial seeds.
+    fn part1(almanac: &Almanac) -> u64 {
+        part1::solve(almanac)
+    }
+
+    /// Lowest location number for any seed in the initial seed ranges.
+    fn part2(almanac: &Almanac) -> u64 {
+        part2::solve(almanac)
+    }
+}
+
+#[test]
+fn test_parse() {
+    let almanac = Day::parse("seeds: 10 20\n\na-to-b map:\n1 2 3\n4 5 6\n").unwrap();
+    assert_eq!(vec![10, 20], almanac.seeds);
+    assert_eq!(vec![vec![[1, 2, 3], [4, 5, 6]]], almanac.maps);
+}
+
+#[test]
+fn test_parse_bad_range() {
+    assert_eq!(
+        "line 4, column 4: expected \" \", found end of line",
+        Day::parse("seeds: 10 20\n\na-to-b map:\n1 2\n")
+            .err()
+            .unwrap()
+            .to_string()
+    );
+}
@@ -1,5 +1,4 @@
-use regex::Regex;
-use std::io;
+use crate::Almanac;
 
 #[derive(Debug, PartialEq)]
 struct RangeMap {
@@ -21,55 +20,33 @@ impl RangeMap {
         }
     }
 
-    fn parse(s: &str) -> RangeMap {
-        let fields = s
-            .split(" ")
-            .map(|f| f.parse::<u64>().expect("Invalid number"))
-            .collect::<Vec<_>>();
-        debug_assert_eq!(3, fields.len());
-        RangeMap { a: fields[1], b: fields[0], len: fields[2] }
+    fn new([b, a, len]: [u64; 3]) -> RangeMap {
+        RangeMap { a, b, len }
     }
 }
 
-fn parse_seeds(s: &str) -> Vec<u64> {
-    debug_assert!(s.starts_with("seeds: "));
-    s[7..].split(" ").map(|n| n.parse::<u64>().unwrap()).collect::<Vec<u64>>()
-}
-
-fn main() {
-    let seeds_re: Regex = Regex::new("^seeds:( \\d+)+$").unwrap();
-    let layer_re: Regex = Regex::new("^\\w+-to-\\w+ map:$").unwrap();
-    let range_re: Regex = Regex::new("^\\d+ \\d+ \\d+$").unwrap();
-
+/// Lowest location number for any of the initial seeds.
+pub fn solve(almanac: &Almanac) -> u64 {
     let mut prev: Vec<u64> = vec![];
-    let mut next: Vec<u64> = vec![];
-
-    for line in io::stdin().lines().map(|l| l.unwrap()) {
-        match line {
-            l if l.is_empty() => continue,
-            l if seeds_re.is_match(&l) => {
-                next = parse_seeds(&l);
-            },
-            l if layer_re.is_match(&l) => {
-                prev.append(&mut next);
-            },
-            l if range_re.is_match(&l) => {
-                let rm = RangeMap::parse(&l);
-                next.extend(prev.iter().filter_map(|p| rm.forward(*p)));
-                prev.retain(|p| !rm.contains(*p));
-            },
-            _ => unreachable!("Invalid line"),
+    let mut next: Vec<u64> = almanac.seeds.clone();
+
+    for map in &almanac.maps {
+        prev.append(&mut next);
+        for &fields in map {
+            let rm = RangeMap::new(fields);
+            next.extend(prev.iter().filter_map(|p| rm.forward(*p)));
+            prev.retain(|p| !rm.contains(*p));
         }
     }
 
     prev.append(&mut next);
 
-    println!("Min: {}", prev.iter().min().unwrap());
+    *prev.iter().min().unwrap()
 }
 
 #[test]
-fn test_range_map_parse() {
-    assert_eq!(RangeMap { a: 20, b: 10, len: 5}, RangeMap::parse("10 20 5"));
+fn test_range_map_new() {
+    assert_eq!(RangeMap { a: 20, b: 10, len: 5}, RangeMap::new([10, 20, 5]));
 }
 
 #[test]
@@ -83,8 +60,3 @@ fn test_range_map_forwards() {
     assert_eq!(Some(22), RangeMap { a: 10, b: 20, len: 5 }.forward(12));
     assert_eq!(None, RangeMap { a: 10, b: 20, len: 5 }.forward(15));
 }
-
-#[test]
-fn test_parse_seeds() {
-    assert_eq!(vec![10, 20, 30], parse_seeds("seeds: 10 20 30"));
-}
@@ -1,5 +1,4 @@
-use regex::Regex;
-use std::io;
+use crate::Almanac;
 
 #[derive(Debug, PartialEq)]
 struct RangeMap {
@@ -43,23 +42,14 @@ impl RangeMap {
         } else {
             RangeMapResult {
                 mapped: None,
-                left: Some(r.clone()),
+                left: Some(*r),
                 right: None,
             }
         }
     }
 
-    fn parse(s: &str) -> RangeMap {
-        let fields = s
-            .split(" ")
-            .map(|f| f.parse::<u64>().expect("Invalid number"))
-            .collect::<Vec<_>>();
-        debug_assert_eq!(3, fields.len());
-        RangeMap {
-            a: fields[1],
-            b: fields[0],
-            len: fields[2],
-        }
+    fn new([b, a, len]: [u64; 3]) -> RangeMap {
+        RangeMap { a, b, len }
     }
 }
 
@@ -77,12 +67,7 @@ struct SeedRange {
 }
 
 impl SeedRange {
-    fn parse_ranges(s: &str) -> Vec<SeedRange> {
-        debug_assert!(s.starts_with("seeds: "));
-        let vals = s[7..]
-            .split(" ")
-            .map(|n| n.parse::<u64>().unwrap())
-            .collect::<Vec<_>>();
+    fn ranges(vals: &[u64]) -> Vec<SeedRange> {
         let mut out = vec![];
         for i in 0..(vals.len() / 2) {
             out.push(SeedRange {
@@ -98,62 +83,50 @@ impl SeedRange {
     }
 }
 
-fn main() {
-    let seeds_re: Regex = Regex::new("^seeds:( \\d+)+$").unwrap();
-    let layer_re: Regex = Regex::new("^\\w+-to-\\w+ map:$").unwrap();
-    let range_re: Regex = Regex::new("^\\d+ \\d+ \\d+$").unwrap();
-
+/// Lowest location number for any seed in the initial seed ranges.
+pub fn solve(almanac: &Almanac) -> u64 {
     let mut prev: Vec<SeedRange> = vec![];
-    let mut next: Vec<SeedRange> = vec![];
-
-    for line in io::stdin().lines().map(|l| l.unwrap()) {
-        match line {
-            l if l.is_empty() => continue,
-            l if seeds_re.is_match(&l) => {
-                next.extend(SeedRange::parse_ranges(&l));
-            }
-            l if layer_re.is_match(&l) => {
-                prev.append(&mut next);
-            }
-            l if range_re.is_match(&l) => {
-                let rm = RangeMap::parse(&l);
-                let mut unmapped = vec![];
-                for r in prev.drain(..) {
-                    if rm.intersects(&r) {
-                        let res = rm.forward(&r);
-                        if let Some(mapped) = res.mapped {
-                            next.push(mapped);
-                        }
-                        if let Some(left) = res.left {
-                            unmapped.push(left);
-                        }
-                        if let Some(right) = res.right {
-                            unmapped.push(right);
-                        }
-                    } else {
-                        unmapped.push(r);
+    let mut next: Vec<SeedRange> = SeedRange::ranges(&almanac.seeds);
+
+    for map in &almanac.maps {
+        prev.append(&mut next);
+        for &fields in map {
+            let rm = RangeMap::new(fields);
+            let mut unmapped = vec![];
+            for r in prev.drain(..) {
+                if rm.intersects(&r) {
+                    let res = rm.forward(&r);
+                    if let Some(mapped) = res.mapped {
+                        next.push(mapped);
+                    }
+                    if let Some(left) = res.left {
+                        unmapped.push(left);
+                    }
+                    if let Some(right) = res.right {
+                        unmapped.push(right);
                     }
+                } else {
+                    unmapped.push(r);
                 }
-                prev.append(&mut unmapped);
             }
-            _ => unreachable!("Invalid line"),
+            prev.append(&mut unmapped);
         }
     }
 
     prev.append(&mut next);
 
-    println!("Min: {}", prev.iter().map(|r| r.start).min().unwrap());
+    prev.iter().map(|r| r.start).min().unwrap()
 }
 
 #[test]
-fn test_range_map_parse() {
+fn test_range_map_new() {
     assert_eq!(
         RangeMap {
             a: 20,
             b: 10,
             len: 5
         },
-        RangeMap::parse("10 20 5")
+        RangeMap::new([10, 20, 5])
     );
 }
 
@@ -252,6 +225,6 @@ fn test_parse_range() {
             SeedRange { start: 10, len: 20 },
             SeedRange { start: 40, len: 5 }
         ],
-        SeedRange::parse_ranges("seeds: 10 20 40 5")
+        SeedRange::ranges(&[10, 20, 40, 5])
     );
 }
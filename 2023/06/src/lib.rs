@@ -0,0 +1,114 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+fn calculate(times: Vec<u64>, distances: Vec<u64>) -> u64 {
+    times
+        .iter()
+        .zip(distances)
+        .map(|(&t, d)| {
+            (0..t).filter(|&i| i as u128 * (t - i) as u128 > d as u128).count()
+        })
+        .product::<usize>() as u64
+}
+
+// The parser has checked that the numbers fit, both on their own and joined up.
+
+fn parse_part1(numbers: &[&str]) -> Vec<u64> {
+    numbers.iter().map(|n| n.parse::<u64>().unwrap()).collect()
+}
+
+fn parse_part2(numbers: &[&str]) -> Vec<u64> {
+    vec![numbers.concat().parse().unwrap()]
+}
+
+/// Parses a line of at least one number with the given label, keeping the digits of each number
+/// as written. Each number has to fit in a `u64`, as do all of their digits joined together.
+fn numbers<'a>(line: &mut Line<'a>, label: &str) -> Result<Vec<&'a str>, ParseError> {
+    line.tag(label)?;
+    let mut numbers = vec![];
+    let mut joined = 0_u64;
+    while !line.at_end() {
+        line.tag(" ")?;
+        line.spaces();
+        let start = *line;
+        let digits = line.take_while(|c| c.is_ascii_digit(), "a number")?;
+        let Ok(n) = digits.parse::<u64>() else {
+            return Err(start.error("a number that fits in 64 bits"));
+        };
+        joined = 10_u64
+            .checked_pow(digits.len() as u32)
+            .and_then(|scale| joined.checked_mul(scale))
+            .and_then(|joined| joined.checked_add(n))
+            .ok_or_else(|| start.error("numbers that fit in 64 bits when joined up"))?;
+        numbers.push(digits);
+    }
+    if numbers.is_empty() {
+        return Err(line.error("a number"));
+    }
+    Ok(numbers)
+}
+
+impl Solution for Day {
+    /// The digits of the times and distances. The two parts read the numbers differently.
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+        let mut lines = parse::lines(input);
+        let mut next = |n| lines.next().ok_or_else(|| ParseError::end_of_input(n, "a line"));
+        let times = next(0)?.parse(|l| numbers(l, "Time:"))?;
+        let distance_line = next(1)?;
+        let distances = distance_line.parse(|l| numbers(l, "Distance:"))?;
+        if distances.len() != times.len() {
+            return Err(distance_line.error_at(
+                distance_line.text().len(),
+                format!("{} distances", times.len()),
+            ));
+        }
+        if let Some(extra) = lines.next() {
+            return Err(extra.error("end of input"));
+        }
+        Ok((times, distances))
+    }
+
+    /// Product of the number of ways to beat the record in each race.
+    fn part1((times, distances): &(Vec<&str>, Vec<&str>)) -> u64 {
+        calculate(parse_part1(times), parse_part1(distances))
+    }
+
+    /// Number of ways to beat the record in the single, long race.
+    fn part2((times, distances): &(Vec<&str>, Vec<&str>)) -> u64 {
+        calculate(parse_part2(times), parse_part2(distances))
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        Ok((vec!["7", "15"], vec!["9", "40"])),
+        Day::parse("Time:      7  15\nDistance:  9  40\n")
+    );
+    assert_eq!(
+        "line 2, column 13: expected 2 distances, found end of line",
+        Day::parse("Time:  7  15\nDistance:  9\n")
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "line 1, column 6: expected a number, found end of line",
+        Day::parse("Time:\nDistance:\n").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 1, column 8: expected a number that fits in 64 bits, found '9'",
+        Day::parse("Time:  99999999999999999999\nDistance:  1\n").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 2, column 23: expected numbers that fit in 64 bits when joined up, found '9'",
+        Day::parse("Time:  1  2\nDistance:  9999999999 9999999999\n")
+            .unwrap_err()
+            .to_string()
+    );
+}
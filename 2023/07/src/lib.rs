@@ -0,0 +1,200 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use once_cell::sync::Lazy;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+pub struct Day;
+
+const PART1_ORDER: &str = "23456789TJQKA";
+static PART1_LUT: Lazy<HashMap<char, u8>> = Lazy::new(|| {
+    PART1_ORDER
+        .char_indices()
+        .map(|(a, b)| (b, a as u8))
+        .collect()
+});
+
+const PART2_ORDER: &str = "J23456789TQKA";
+static PART2_LUT: Lazy<HashMap<char, u8>> = Lazy::new(|| {
+    PART2_ORDER
+        .char_indices()
+        .map(|(a, b)| (b, a as u8))
+        .collect()
+});
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hand {
+    cards: String,
+    bet: u32,
+}
+
+impl Hand {
+    fn parse(line: &mut Line) -> Result<Hand, ParseError> {
+        let start = *line;
+        let cards = line.take_while(|c| PART1_ORDER.contains(c), "a card")?;
+        if cards.len() != 5 {
+            return Err(start.error("five cards"));
+        }
+        line.tag(" ")?;
+        Ok(Hand {
+            cards: cards.to_string(),
+            bet: line.number()?,
+        })
+    }
+
+    fn sort_key(&self, jokers: bool) -> (String, Vec<u8>) {
+        (hand_type(&self.cards, jokers), card_scores(&self.cards, jokers))
+    }
+}
+
+fn card_scores(cards: &str, jokers: bool) -> Vec<u8> {
+    assert_eq!(5, cards.len());
+    let lut = if jokers { &PART2_LUT } else { &PART1_LUT };
+    cards.chars().map(|c| lut[&c]).collect()
+}
+
+fn hand_type(cards: &str, jokers: bool) -> String {
+    assert_eq!(5, cards.len());
+    let mut hist: HashMap<char, u32> = HashMap::new();
+    let mut num_jokers = 0;
+    for card in cards.chars() {
+        if jokers && card == 'J' {
+            num_jokers += 1;
+        } else if let Some(x) = hist.get_mut(&card) {
+            *x += 1;
+        } else {
+            hist.insert(card, 1);
+        }
+    }
+    let mut vals = hist.into_values().collect::<Vec<_>>();
+    vals.sort_by_key(|a| Reverse(*a));
+    if jokers {
+        if vals.is_empty() {
+            vals.push(num_jokers);
+        } else {
+            *vals.get_mut(0).unwrap() += num_jokers;
+        }
+    }
+    vals.into_iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+fn rank(hands: &mut [Hand], jokers: bool) {
+    hands.sort_by_cached_key(|h| h.sort_key(jokers));
+}
+
+fn score(hands: &[Hand]) -> u32 {
+    let mut total = 0;
+    for (i, hand) in hands.iter().enumerate() {
+        total += (i as u32 + 1) * hand.bet;
+    }
+    total
+}
+
+fn total_winnings(hands: &[Hand], jokers: bool) -> u32 {
+    let mut hands = hands.to_vec();
+    rank(&mut hands, jokers);
+    score(&hands)
+}
+
+impl Solution for Day {
+    type Input<'a> = Vec<Hand>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+        parse::each_line(input, Hand::parse)
+    }
+
+    /// Total winnings of the set of hands.
+    fn part1(hands: &Vec<Hand>) -> u32 {
+        total_winnings(hands, false)
+    }
+
+    /// Total winnings when `J` cards are jokers.
+    fn part2(hands: &Vec<Hand>) -> u32 {
+        total_winnings(hands, true)
+    }
+}
+
+#[cfg(test)]
+fn hand(s: &str) -> Hand {
+    Hand::parse(&mut Line::new(1, s)).unwrap()
+}
+
+#[test]
+fn test_hand_parse() {
+    assert_eq!(
+        Hand {
+            cards: "23456".to_string(),
+            bet: 123,
+        },
+        hand("23456 123")
+    );
+    assert_eq!(
+        "line 1, column 1: expected five cards, found '2'",
+        Hand::parse(&mut Line::new(1, "2345 123"))
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn test_card_scores() {
+    assert_eq!(card_scores("23456", false), vec![0, 1, 2, 3, 4]);
+    assert_eq!(card_scores("TJQKA", false), vec![8, 9, 10, 11, 12])
+}
+
+#[test]
+fn test_card_scores_jokers() {
+    assert_eq!(card_scores("23456", true), vec![1, 2, 3, 4, 5]);
+    assert_eq!(card_scores("TJQKA", true), vec![9, 0, 10, 11, 12])
+}
+
+#[test]
+fn test_hand_type() {
+    assert_eq!("11111", hand_type("23456", false));
+    assert_eq!("311", hand_type("22256", false));
+    assert_eq!("221", hand_type("22344", false));
+    assert_eq!("32", hand_type("22244", false));
+    assert_eq!("2111", hand_type("22345", false));
+    assert_eq!("5", hand_type("AAAAA", false));
+}
+
+#[test]
+fn test_hand_type_jokers() {
+    assert_eq!("11111", hand_type("23456", true));
+    assert_eq!("311", hand_type("22J56", true));
+    assert_eq!("221", hand_type("22344", true));
+    assert_eq!("32", hand_type("22J44", true));
+    assert_eq!("2111", hand_type("2J345", true));
+    assert_eq!("5", hand_type("AAAAJ", true));
+}
+
+#[test]
+fn test_hand_rank() {
+    let a = hand("32T3K 765");
+    let b = hand("T55J5 684");
+    let c = hand("KK677 28");
+    let d = hand("KTJJT 220");
+    let e = hand("QQQJA 483");
+
+    let mut ranked = vec![a.clone(), b.clone(), c.clone(), d.clone(), e.clone()];
+    rank(&mut ranked, false);
+    assert_eq!(vec![a, d, c, b, e], ranked);
+}
+
+#[test]
+fn test_hand_rank_jokers() {
+    let a = hand("32T3K 765");
+    let b = hand("T55J5 684");
+    let c = hand("KK677 28");
+    let d = hand("KTJJT 220");
+    let e = hand("QQQJA 483");
+
+    let mut ranked = vec![a.clone(), b.clone(), c.clone(), d.clone(), e.clone()];
+    rank(&mut ranked, true);
+    assert_eq!(vec![a, c, b, e, d], ranked);
+}
@@ -0,0 +1,104 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use std::collections::HashMap;
+
+pub struct Day;
+
+/// The left/right instructions, and the left and right destinations of each node.
+type Network = (String, HashMap<String, (String, String)>);
+
+/// Parses a node, e.g. `AAA = (BBB, CCC)`.
+fn parse_node(line: &mut Line) -> Result<(String, (String, String)), ParseError> {
+    let name = line.word()?.to_string();
+    line.tag(" = (")?;
+    let left = line.word()?.to_string();
+    line.tag(", ")?;
+    let right = line.word()?.to_string();
+    line.tag(")")?;
+    Ok((name, (left, right)))
+}
+
+fn parse_input(input: &str) -> Result<Network, ParseError> {
+    let blocks = parse::blocks(input);
+    let [directions, nodes] = blocks.as_slice() else {
+        return Err(match blocks.get(2) {
+            Some(extra) => extra[0].error("end of input"),
+            None => ParseError::end_of_input(input.lines().count(), "directions and nodes"),
+        });
+    };
+    if let Some(extra) = directions.get(1) {
+        return Err(extra.error("a blank line"));
+    }
+    let directions = directions[0].parse(|l| l.take_while(|c| "LR".contains(c), "L or R"))?;
+    let nodes = nodes
+        .iter()
+        .map(|line| line.parse(parse_node))
+        .collect::<Result<_, _>>()?;
+    Ok((directions.to_string(), nodes))
+}
+
+fn step_count(
+    directions: &str,
+    nodes: &HashMap<String, (String, String)>,
+    start: &str,
+    end: fn(&str) -> bool,
+) -> u64 {
+    let mut position: String = start.to_string();
+    let mut counter: u64 = 0;
+    loop {
+        let direction = directions
+            .chars()
+            .nth((counter % directions.len() as u64) as usize)
+            .unwrap();
+        let (left, right) = nodes.get(&position).unwrap();
+        position = match direction {
+            'L' => left.clone(),
+            'R' => right.clone(),
+            _ => unreachable!("bad direction"),
+        };
+        counter += 1;
+        if end(&position) {
+            return counter;
+        }
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    let (mut gcd, mut tmp) = (a, b);
+    while tmp != 0 {
+        (gcd, tmp) = (tmp, gcd % tmp);
+    }
+    (a / gcd) * b
+}
+
+impl Solution for Day {
+    type Input<'a> = Network;
+    type Part1 = u64;
+    type Part2 = u64;
+    const SHAPE: Shape = Shape::Blocks;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        parse_input(input)
+    }
+
+    /// Steps needed to get from `AAA` to `ZZZ`.
+    fn part1((directions, nodes): &Network) -> u64 {
+        assert!(
+            nodes.contains_key("AAA") && nodes.contains_key("ZZZ"),
+            "Missing AAA or ZZZ node"
+        );
+        step_count(directions, nodes, "AAA", |p| p == "ZZZ")
+    }
+
+    /// Steps needed before every path starting at a `..A` node is on a `..Z` node
+    /// simultaneously.
+    fn part2((directions, nodes): &Network) -> u64 {
+        nodes
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|k| step_count(directions, nodes, k, |p| p.ends_with('Z')))
+            .reduce(lcm)
+            .unwrap()
+    }
+}
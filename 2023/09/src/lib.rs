@@ -0,0 +1,49 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+fn calculate_next(seq: &[i32]) -> i32 {
+    assert!(seq.len() > 1);
+
+    let mut diffs: Vec<i32> = Vec::with_capacity(seq.len() - 1);
+    for i in 0..(seq.len() - 1) {
+        diffs.push(seq[i + 1] - seq[i]);
+    }
+
+    if diffs.iter().all(|x| *x == 0) {
+        *seq.last().unwrap()
+    } else {
+        seq.last().unwrap() + calculate_next(&diffs)
+    }
+}
+
+impl Solution for Day {
+    /// The history of each value.
+    type Input<'a> = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        parse::each_line(input, |l| l.list(" ", |l| l.number()))
+    }
+
+    /// Sum of the extrapolated next values.
+    fn part1(lines: &Vec<Vec<i32>>) -> i32 {
+        lines.iter().map(|l| calculate_next(l)).sum::<i32>()
+    }
+
+    /// Sum of the extrapolated previous values.
+    fn part2(lines: &Vec<Vec<i32>>) -> i32 {
+        let mut lines = lines.clone();
+        lines.iter_mut().for_each(|l| l.reverse());
+        lines.iter().map(|l| calculate_next(l)).sum::<i32>()
+    }
+}
+
+#[test]
+fn test_calculate_next() {
+    assert_eq!(18, calculate_next(&[0, 3, 6, 9, 12, 15]));
+    assert_eq!(28, calculate_next(&[1, 3, 6, 10, 15, 21]));
+    assert_eq!(68, calculate_next(&[10, 13, 16, 21, 30, 45]));
+}
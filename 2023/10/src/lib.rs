@@ -0,0 +1,139 @@
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::search::{self, Explored};
+use aoc_core::Solution;
+
+pub struct Day;
+
+pub struct Field {
+    tiles: Grid<char>,
+}
+
+impl Field {
+    fn parse(input: &str) -> Result<Field, ParseError> {
+        let tiles = Grid::parse(input, "|-LJ7F.S", |c| c)?;
+        if tiles.find(&'S').is_none() {
+            return Err(ParseError::end_of_input(tiles.height(), "a start point 'S'"));
+        }
+        Ok(Field { tiles })
+    }
+
+    /// Follows the loop both ways from the start, settling each of its tiles at its distance
+    /// from the start.
+    fn visit(&self) -> Explored<Pos> {
+        let start = self.start_pos();
+        let successors = |&p: &Pos| match p == start {
+            true => self
+                .tiles
+                .neighbours4(start)
+                .filter(|&n| self.connections(n).contains(&start))
+                .collect(),
+            false => self.connections(p),
+        };
+        search::bfs([start], successors, |_| false, |_| {})
+    }
+
+    fn max_path_len(&self) -> usize {
+        self.visit().settled().map(|(_, l)| l).max().unwrap() as usize
+    }
+
+    fn num_enclosed(&self) -> usize {
+        let visited = self.visit();
+
+        let mut count = 0;
+
+        let mut is_inside = false;
+
+        for (p, &tile) in self.tiles.iter() {
+            let mut tile = tile;
+            if tile == 'S' {
+                tile = self.infer_tile(p);
+            } else if visited.cost(&p).is_none() {
+                tile = '.'; // Treat disconnected pipes as spaces.
+            }
+            match tile {
+                '-' | 'F' | '7' => (),
+                '|' | 'L' | 'J' => is_inside = !is_inside,
+                '.' => {
+                    if is_inside {
+                        count += 1
+                    }
+                }
+                _ => unreachable!("Invalid tile"),
+            }
+        }
+
+        count
+    }
+
+    fn start_pos(&self) -> Pos {
+        self.tiles.find(&'S').expect("Missing start point")
+    }
+
+    fn connections(&self, p: Pos) -> Vec<Pos> {
+        let steps: &[(isize, isize)] = match self.tiles[p] {
+            'F' => &[(0, 1), (1, 0)],
+            'L' => &[(0, 1), (-1, 0)],
+            '7' => &[(0, -1), (1, 0)],
+            'J' => &[(0, -1), (-1, 0)],
+            '-' => &[(0, -1), (0, 1)],
+            '|' => &[(-1, 0), (1, 0)],
+            _ => &[],
+        };
+        steps.iter().filter_map(|&d| self.tiles.offset(p, d)).collect()
+    }
+
+    // Work out what kind of tile is at a given position -- this is useful for turning an 'S' tile
+    // into its actual type.
+    fn infer_tile(&self, p: Pos) -> char {
+        let adj = self
+            .tiles
+            .neighbours4(p)
+            .filter(|n| self.connections(*n).contains(&p))
+            .collect::<Vec<_>>();
+        assert_eq!(2, adj.len()); // Tiles in the loop *must* have two connections.
+        // The neighbours come in reading order: above, left, right, then below.
+        let ((r, c), (r1, c1), (r2, c2)) = (p, adj[0], adj[1]);
+        if c1 == c2 {
+            '|'
+        } else if r1 == r2 {
+            '-'
+        } else if r1 < r {
+            if c2 < c { 'J' } else { 'L' }
+        } else if c1 < c {
+            '7'
+        } else {
+            'F'
+        }
+    }
+}
+
+impl Solution for Day {
+    type Input<'a> = Field;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Field, ParseError> {
+        Field::parse(input)
+    }
+
+    /// Number of steps to the point in the loop farthest from the start.
+    fn part1(field: &Field) -> usize {
+        field.max_path_len()
+    }
+
+    /// Number of tiles enclosed by the loop.
+    fn part2(field: &Field) -> usize {
+        field.num_enclosed()
+    }
+}
+
+#[test]
+fn test_parse_ragged() {
+    assert_eq!(
+        "line 2, column 3: expected a row of 3 cells, found end of line",
+        Field::parse("S-7\n|.\nL-J\n").err().unwrap().to_string()
+    );
+}
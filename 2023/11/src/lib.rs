@@ -0,0 +1,84 @@
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
+use std::collections::HashSet;
+
+pub struct Day;
+
+pub struct Image {
+    points: Grid<char>,
+}
+
+impl Image {
+    fn parse(input: &str) -> Result<Image, ParseError> {
+        let points = Grid::parse(input, ".#", |c| c)?;
+        Ok(Image { points })
+    }
+
+    fn empty_rows(&self) -> HashSet<usize> {
+        (0..self.points.height())
+            .filter(|r| self.points.row(*r).iter().all(|c| *c == '.'))
+            .collect()
+    }
+
+    fn empty_columns(&self) -> HashSet<usize> {
+        (0..self.points.width())
+            .filter(|c| self.points.column(*c).all(|p| *p == '.'))
+            .collect()
+    }
+
+    fn stars(&self) -> Vec<Pos> {
+        self.points.find_all(&'#').collect()
+    }
+
+    fn calculate_paths(&self) -> (usize, usize) {
+        let empty_rows = self.empty_rows();
+        let empty_columns = self.empty_columns();
+        let stars = self.stars();
+
+        let mut path_length = 0;
+        let mut expanded_steps = 0;
+
+        for i in 0..stars.len() {
+            for j in (i + 1)..stars.len() {
+                let (p1, p2) = (stars[i], stars[j]);
+                let (r1, r2, c1, c2) = (
+                    p1.0.min(p2.0),
+                    p1.0.max(p2.0),
+                    p1.1.min(p2.1),
+                    p1.1.max(p2.1),
+                );
+                path_length += (r2 - r1) + (c2 - c1);
+                expanded_steps += (r1..r2).filter(|r| empty_rows.contains(r)).count()
+                    + (c1..c2).filter(|c| empty_columns.contains(c)).count();
+            }
+        }
+
+        (path_length, expanded_steps)
+    }
+}
+
+impl Solution for Day {
+    type Input<'a> = Image;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Image, ParseError> {
+        Image::parse(input)
+    }
+
+    /// Sum of the shortest paths between every pair of galaxies, with empty space doubled.
+    fn part1(image: &Image) -> usize {
+        let (l, s) = image.calculate_paths();
+        l + s
+    }
+
+    /// Sum of the shortest paths between every pair of galaxies, with empty space a million
+    /// times larger.
+    fn part2(image: &Image) -> usize {
+        let (l, s) = image.calculate_paths();
+        l + s * 999_999
+    }
+}
@@ -0,0 +1,124 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use cached::proc_macro::cached;
+
+pub struct Day;
+
+#[cached]
+fn num_arrangements(row: String, hash_counter: usize, groups: Vec<usize>) -> usize {
+    if row.is_empty() {
+        if groups.is_empty() || groups.len() == 1 && groups[0] == hash_counter {
+            return 1;
+        }
+        0
+    } else if let Some(rest) = row.strip_prefix('#') {
+        if groups.is_empty() || groups[0] < (hash_counter + 1) {
+            return 0;
+        }
+        num_arrangements(rest.to_string(), hash_counter + 1, groups)
+    } else if let Some(rest) = row.strip_prefix('.') {
+        if hash_counter > 0 {
+            if groups.is_empty() || groups[0] != hash_counter {
+                return 0;
+            }
+            num_arrangements(rest.to_string(), 0, groups[1..].to_vec())
+        } else {
+            num_arrangements(rest.to_string(), 0, groups)
+        }
+    } else if row.starts_with('?') {
+        num_arrangements(row.replacen('?', "#", 1), hash_counter, groups.clone())
+            + num_arrangements(row.replacen('?', ".", 1), hash_counter, groups)
+    } else {
+        unreachable!("invalid char");
+    }
+}
+
+fn parse(line: &mut Line) -> Result<(String, Vec<usize>), ParseError> {
+    let left = line.take_while(|c| ".#?".contains(c), "one of \".#?\"")?;
+    line.tag(" ")?;
+    let right = line.list(",", |l| l.number())?;
+    Ok((String::from(left), right))
+}
+
+impl Solution for Day {
+    /// The springs in each row, and the sizes of the groups of damaged springs.
+    type Input<'a> = Vec<(String, Vec<usize>)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(String, Vec<usize>)>, ParseError> {
+        parse::each_line(input, parse)
+    }
+
+    /// Sum of the possible arrangements for each row.
+    fn part1(rows: &Vec<(String, Vec<usize>)>) -> usize {
+        rows.iter()
+            .map(|(left, right)| num_arrangements(left.clone(), 0, right.clone()))
+            .sum()
+    }
+
+    /// Sum of the possible arrangements for each row once unfolded.
+    fn part2(rows: &Vec<(String, Vec<usize>)>) -> usize {
+        rows.iter()
+            .map(|(left, right)| {
+                let (left5, right5) = (
+                    [left.as_str(); 5].join("?"),
+                    right.iter().cycle().take(right.len() * 5).copied().collect(),
+                );
+                num_arrangements(left5, 0, right5)
+            })
+            .sum()
+    }
+}
+
+#[test]
+fn test_num_arrangements() {
+    assert_eq!(
+        1,
+        num_arrangements(String::from("???.###"), 0, vec![1, 1, 3])
+    );
+    assert_eq!(
+        4,
+        num_arrangements(String::from(".??..??...?##."), 0, vec![1, 1, 3])
+    );
+    assert_eq!(
+        10,
+        num_arrangements(String::from("?###????????"), 0, vec![3, 2, 1])
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::num_arrangements;
+    use proptest::prelude::*;
+
+    /// Counts the arrangements by trying every way of filling in the unknown springs.
+    fn enumerate(row: &str, groups: &[usize]) -> usize {
+        let unknown: Vec<usize> = row.match_indices('?').map(|(i, _)| i).collect();
+        (0..1_u32 << unknown.len())
+            .filter(|bits| {
+                let mut springs = row.as_bytes().to_vec();
+                for (n, &i) in unknown.iter().enumerate() {
+                    springs[i] = if bits & 1 << n != 0 { b'#' } else { b'.' };
+                }
+                let runs: Vec<usize> = springs
+                    .split(|&c| c == b'.')
+                    .filter(|run| !run.is_empty())
+                    .map(|run| run.len())
+                    .collect();
+                runs == groups
+            })
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn test_num_arrangements_matches_enumerate(
+            row in "[.#?]{1,12}",
+            groups in prop::collection::vec(1..5_usize, 1..5),
+        ) {
+            let got = num_arrangements(row.clone(), 0, groups.clone());
+            prop_assert_eq!(enumerate(&row, &groups), got);
+        }
+    }
+}
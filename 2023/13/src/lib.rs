@@ -0,0 +1,188 @@
+use aoc_core::grid::{Grid, Orientation, View};
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+pub struct Pattern {
+    /// Each row as a bitfield, read left to right.
+    rows: Vec<u64>,
+    /// Each column as a bitfield, read top to bottom.
+    cols: Vec<u64>,
+}
+
+impl Pattern {
+    fn parse(row_strs: &[String]) -> Pattern {
+        let grid = Grid::from_rows(row_strs.iter().map(|s| s.chars().map(|c| c == '#')));
+        // The columns are the rows of the pattern turned on its side.
+        Pattern {
+            rows: bitfields(grid.view(Orientation::Identity)),
+            cols: bitfields(grid.view(Orientation::Transpose)),
+        }
+    }
+
+    fn reflections(&self) -> usize {
+        100 * find_reflection(&self.rows, 0) + find_reflection(&self.cols, 0)
+    }
+
+    fn smudgy_reflections(&self) -> usize {
+        100 * find_reflection(&self.rows, 1) + find_reflection(&self.cols, 1)
+    }
+}
+
+/// Turns each row of the view into a bitfield.
+fn bitfields(view: View<bool>) -> Vec<u64> {
+    view.rows()
+        .map(|row| row.fold(0, |a, &b| (a << 1) + b as u64))
+        .collect()
+}
+
+/// Finds the line of reflection between two rows, counting the rows before it, for which the
+/// rows mirrored across it differ in exactly `smudges` places. Returns 0 if there's none.
+fn find_reflection(lines: &[u64], smudges: u32) -> usize {
+    for i in 1..lines.len() {
+        let l = i.min(lines.len() - i);
+        // Count bits that differ between each row and its mirror image.
+        let differences: u32 = lines[(i - l)..i]
+            .iter()
+            .rev()
+            .zip(&lines[i..(i + l)])
+            .map(|(u, v)| (u ^ v).count_ones())
+            .sum();
+        if differences == smudges {
+            return i;
+        }
+    }
+    0
+}
+
+/// The most rows or columns a pattern can have, so that each fits in a bitfield.
+const MAX_SIZE: usize = 64;
+
+/// Checks that a block is a rectangle of `.` and `#`, small enough to fit each row and column in
+/// a bitfield.
+fn parse_rows(block: &[Line]) -> Result<Vec<String>, ParseError> {
+    if let Some(line) = block.get(MAX_SIZE) {
+        let expected = format!("the end of the pattern, at most {} rows", MAX_SIZE);
+        return Err(line.error(expected));
+    }
+    let width = block[0].text().len().min(MAX_SIZE);
+    block
+        .iter()
+        .map(|line| {
+            line.parse(|l| {
+                let cells = "one of \".#\"";
+                let row = l.take_while(|c| c == '.' || c == '#', cells)?;
+                if !l.at_end() {
+                    return Err(l.error(cells));
+                }
+                if row.len() != width {
+                    let expected = format!("a row of {} cells", width);
+                    return Err(l.error_at(row.len().min(width), expected));
+                }
+                Ok(row.to_string())
+            })
+        })
+        .collect()
+}
+
+fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    parse::blocks(input)
+        .iter()
+        .map(|block| Ok(Pattern::parse(&parse_rows(block)?)))
+        .collect()
+}
+
+impl Solution for Day {
+    type Input<'a> = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::Grids;
+
+    fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+        parse(input)
+    }
+
+    /// Summary of the lines of reflection in every pattern.
+    fn part1(patterns: &Vec<Pattern>) -> usize {
+        patterns.iter().map(|i| i.reflections()).sum()
+    }
+
+    /// Summary of the lines of reflection once each pattern's smudge has been fixed.
+    fn part2(patterns: &Vec<Pattern>) -> usize {
+        patterns.iter().map(|i| i.smudgy_reflections()).sum()
+    }
+}
+
+#[test]
+fn test_row_reflections() {
+    let input = Pattern::parse(&["#.##..##.".to_string(),
+        "..#.##.#.".to_string(),
+        "##......#".to_string(),
+        "##......#".to_string(),
+        "..#.##.#.".to_string(),
+        "..##..##.".to_string(),
+        "#.#.##.#.".to_string()]);
+    assert_eq!(5, input.reflections());
+}
+
+#[test]
+fn test_col_reflections() {
+    let input = Pattern::parse(&["#...##..#".to_string(),
+        "#....#..#".to_string(),
+        "..##..###".to_string(),
+        "#####.##.".to_string(),
+        "#####.##.".to_string(),
+        "..##..###".to_string(),
+        "#....#..#".to_string()]);
+    assert_eq!(400, input.reflections());
+}
+
+#[test]
+fn test_col_smudgy_reflections() {
+    let input = Pattern::parse(&["#.##..##.".to_string(),
+        "..#.##.#.".to_string(),
+        "##......#".to_string(),
+        "##......#".to_string(),
+        "..#.##.#.".to_string(),
+        "..##..##.".to_string(),
+        "#.#.##.#.".to_string()]);
+    assert_eq!(300, input.smudgy_reflections());
+}
+
+#[test]
+fn test_parse() {
+    let input = Pattern::parse(&["#.#".to_string(),
+        ".#.".to_string(),
+        "##.".to_string()]);
+    assert_eq!(
+        vec![0b101, 0b11, 0b100],
+        input.cols
+    );
+    assert_eq!(
+        vec![0b101, 0b10, 0b110],
+        input.rows
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        "line 2, column 2: expected one of \".#\", found 'x'",
+        parse("#.\n.x\n").err().unwrap().to_string()
+    );
+    assert_eq!(
+        "line 5, column 3: expected a row of 2 cells, found '#'",
+        parse("#.\n.#\n\n##\n..#\n").err().unwrap().to_string()
+    );
+    assert_eq!(
+        "line 1, column 65: expected a row of 64 cells, found '#'",
+        parse(&"#".repeat(65)).err().unwrap().to_string()
+    );
+    assert_eq!(
+        "line 67, column 1: expected the end of the pattern, at most 64 rows, found '#'",
+        parse(&format!("..\n\n{}", "#.\n".repeat(65))).err().unwrap().to_string()
+    );
+    assert!(parse(&"#.\n".repeat(64)).is_ok());
+}
@@ -0,0 +1,41 @@
+//! Random platforms of any size, for stress testing.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A square platform `size` tiles wide (at least one), with roughly a quarter of the tiles
+/// rounded rocks and a sixth cube-shaped rocks, like the real inputs.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(match rng.gen_range(0..12) {
+                0..=2 => 'O',
+                3..=4 => '#',
+                _ => '.',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 20);
+        assert_eq!(input, generate(1, 20));
+        // No rock can put more load on the north beams than the platform is long.
+        let max = 20 * input.matches('O').count();
+        let platform = Day::parse(&input).unwrap();
+        assert!(Day::part1(&platform) <= max);
+        assert!(Day::part2(&platform) <= max);
+    }
+}
@@ -0,0 +1,107 @@
+use aoc_core::grid::{Grid, Orientation, ViewMut};
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
+use std::collections::HashMap;
+
+mod generate;
+mod visualise;
+
+pub use generate::generate;
+pub use visualise::visualise;
+
+pub struct Day;
+
+const ITERATIONS: usize = 1_000_000_000;
+
+/// The views of the platform with north, west, south and east at the top.
+const TILTS: [Orientation; 4] = [
+    Orientation::Identity,
+    Orientation::Transpose,
+    Orientation::FlipVertical,
+    Orientation::Rotate270,
+];
+
+/// Tilts the platform north, west, south and east in turn, showing `tilted` the platform after
+/// each tilt.
+fn cycle(input: Grid<char>, mut tilted: impl FnMut(&Grid<char>)) -> Grid<char> {
+    let mut map = input.clone();
+
+    for orientation in TILTS {
+        roll(&mut map.view_mut(orientation));
+        tilted(&map);
+    }
+
+    map
+}
+
+/// Rolls every rounded rock as far towards the top of the view as it will go.
+fn roll(map: &mut ViewMut<char>) {
+    let (height, width) = (map.height(), map.width());
+    for c in 0..width {
+        let mut last_space: Option<usize> = None;
+        for r in 0..height {
+            match map[(r, c)] {
+                '.' => {
+                    if last_space.is_none() {
+                        last_space = Some(r);
+                    }
+                },
+                '#' => last_space = None,
+                'O' => {
+                    if let Some(r0) = last_space {
+                        (map[(r0, c)], map[(r, c)], last_space) = ('O', '.', Some(r0 + 1));
+                    }
+                },
+                _ => unreachable!("Invalid tile")
+            }
+        }
+    }
+}
+
+fn calculate_load(map: &Grid<char>) -> usize {
+    map.find_all(&'O').map(|(r, _)| map.height() - r).sum()
+}
+
+impl Solution for Day {
+    /// The platform.
+    type Input<'a> = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input, ".#O", |c| c)
+    }
+
+    /// Total load on the north support beams after tilting the platform north.
+    fn part1(map: &Grid<char>) -> usize {
+        let mut part1_map = map.clone();
+        roll(&mut part1_map.view_mut(Orientation::Identity));
+        calculate_load(&part1_map)
+    }
+
+    /// Total load on the north support beams after a billion spin cycles.
+    fn part2(map: &Grid<char>) -> usize {
+        spin(map)
+    }
+}
+
+fn spin(map: &Grid<char>) -> usize {
+    let mut cache: HashMap<Grid<char>, usize> = HashMap::new();
+    let mut cycle_discovered = false;
+    let mut i = 0;
+    let mut part2_map = map.clone();
+    while i < ITERATIONS {
+        if !cycle_discovered {
+            if let Some(j) = cache.get(&part2_map) {
+                i = ITERATIONS - ((ITERATIONS - i) % (i - j));
+                cycle_discovered = true;
+            }
+        }
+        cache.insert(part2_map.clone(), i);
+        part2_map = cycle(part2_map, |_| {});
+        i += 1;
+    }
+    calculate_load(&part2_map)
+}
@@ -0,0 +1,52 @@
+//! Pictures of the rocks rolling around the platform.
+
+use crate::{cycle, Day};
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+use aoc_core::render::{Frame, Rgb};
+use aoc_core::Solution;
+use std::collections::HashSet;
+
+/// Empty space, cube-shaped rocks and rounded rocks.
+const PALETTE: &[Rgb] = &[[16, 16, 24], [96, 96, 112], [230, 160, 60]];
+
+fn draw(map: &Grid<char>) -> Frame {
+    let mut frame = Frame::new(map.width(), map.height(), PALETTE);
+    for ((y, x), &tile) in map.iter() {
+        match tile {
+            '#' => frame.set(x, y, 1),
+            'O' => frame.set(x, y, 2),
+            _ => {}
+        }
+    }
+    frame
+}
+
+/// Draws the platform, then draws it again after each tilt of the spin cycles, until a cycle
+/// leaves it as an earlier one did and the rest would only repeat.
+pub fn visualise(input: &str, frame: &mut dyn FnMut(&Frame)) -> Result<(), ParseError> {
+    let mut map = Day::parse(input)?;
+    frame(&draw(&map));
+    let mut seen = HashSet::new();
+    while seen.insert(map.clone()) {
+        map = cycle(map, |tilted| frame(&draw(tilted)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::visualise;
+    use aoc_core::example;
+
+    #[test]
+    fn test_visualise() {
+        let mut frames = vec![];
+        visualise(&example!(), &mut |f| frames.push(f.clone())).unwrap();
+        // The example starts repeating after 10 cycles.
+        assert_eq!(1 + 4 * 10, frames.len());
+        assert!(frames.iter().all(|f| (f.width(), f.height()) == (10, 10)));
+        assert_eq!(2, frames[0].get(0, 0));
+        assert_eq!(1, frames[0].get(5, 0));
+    }
+}
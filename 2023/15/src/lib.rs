@@ -0,0 +1,105 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+fn hash(s: &str) -> u8 {
+    s.bytes()
+        .fold(0, |a, b| (a.wrapping_add(b)).wrapping_mul(17))
+}
+
+/// Checks that a step is a label followed by `-` or by `=` and a focal length, returning its text.
+fn parse_step<'a>(line: &mut Line<'a>) -> Result<&'a str, ParseError> {
+    let start = line.rest();
+    line.take_while(|c| c.is_ascii_lowercase(), "a label")?;
+    if line.one_of("-=")? == '=' {
+        line.number::<u32>()?;
+    }
+    Ok(&start[..start.len() - line.rest().len()])
+}
+
+impl Solution for Day {
+    /// The steps of the initialization sequence.
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = u32;
+    type Part2 = u32;
+    const SHAPE: Shape = Shape::SingleLine;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse::single_line(input, |l| l.list(",", parse_step))
+    }
+
+    /// Sum of the HASH of every step in the initialization sequence.
+    fn part1(steps: &Vec<&str>) -> u32 {
+        steps.iter().map(|s| hash(s) as u32).sum()
+    }
+
+    /// Focusing power of the lens configuration once every step has been applied.
+    fn part2(steps: &Vec<&str>) -> u32 {
+        focusing_power(steps)
+    }
+}
+
+fn focusing_power(steps: &[&str]) -> u32 {
+    let mut hashmap: Vec<Vec<(String, u32)>> = vec![vec![]; 256];
+    'a: for &s in steps {
+        if let Some(k) = s.strip_suffix('-') {
+            let h = hash(k) as usize;
+            for i in 0..hashmap[h].len() {
+                if hashmap[h][i].0 == k {
+                    hashmap[h].remove(i);
+                    break;
+                }
+            }
+        } else {
+            let (k, v) = s.split_once('=').unwrap();
+            let n = v.parse::<u32>().unwrap();
+            let h = hash(k) as usize;
+            for i in 0..hashmap[h].len() {
+                if hashmap[h][i].0 == k {
+                    hashmap[h][i].1 = n;
+                    continue 'a;
+                }
+            }
+            hashmap[h].push((k.to_string(), n));
+        }
+    }
+    hashmap
+        .iter()
+        .zip(1..257)
+        .map(|(b, i)| {
+            b.iter()
+                .zip(1..b.len() + 1)
+                .map(|((_, n), j)| n * j as u32)
+                .sum::<u32>()
+                * i as u32
+        })
+        .sum::<u32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day;
+    use aoc_core::Solution;
+
+    const TEST_INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(1320, Day::part1(&Day::parse(TEST_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(145, Day::part2(&Day::parse(TEST_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_bad_step() {
+        assert_eq!(
+            "line 1, column 9: expected a number, found ','",
+            Day::parse("rn=1,cm=,qp=3").unwrap_err().to_string()
+        );
+    }
+}
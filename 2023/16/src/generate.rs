@@ -0,0 +1,40 @@
+//! Random contraptions of any size, for stress testing.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A square contraption `size` tiles wide (at least one), with roughly one tile in ten a mirror
+/// or splitter.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(match rng.gen_range(0..40) {
+                0 => '/',
+                1 => '\\',
+                2 => '|',
+                3 => '-',
+                _ => '.',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 30);
+        assert_eq!(input, generate(1, 30));
+        let grid = Day::parse(&input).unwrap();
+        assert!(Day::part1(&grid) <= Day::part2(&grid));
+    }
+}
@@ -0,0 +1,128 @@
+use aoc_core::geom::{Dir4, DirSet};
+use aoc_core::grid::Grid;
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
+use std::collections::VecDeque;
+
+mod generate;
+mod visualise;
+
+pub use generate::generate;
+pub use visualise::visualise;
+
+pub struct Day;
+
+pub struct Map {
+    tiles: Grid<char>,
+}
+
+impl Map {
+    fn parse(s: &str) -> Result<Map, ParseError> {
+        let tiles = Grid::parse(s, ".|-/\\", |c| c)?;
+        Ok(Map { tiles })
+    }
+
+    fn next(&self, row: usize, col: usize, dir: Dir4) -> Vec<(usize, usize, Dir4)> {
+        let dirs = match self.tiles[(row, col)] {
+            '.' => vec![dir],
+            '\\' if dir.is_vertical() => vec![dir.turn_left()],
+            '\\' => vec![dir.turn_right()],
+            '/' if dir.is_vertical() => vec![dir.turn_right()],
+            '/' => vec![dir.turn_left()],
+            '-' if dir.is_vertical() => vec![Dir4::West, Dir4::East],
+            '|' if !dir.is_vertical() => vec![Dir4::North, Dir4::South],
+            '-' | '|' => vec![dir],
+            _ => unreachable!("Bad tile"),
+        };
+        dirs.into_iter()
+            .filter_map(|d| self.tiles.step((row, col), d).map(|(r, c)| (r, c, d)))
+            .collect()
+    }
+
+    /// Counts the tiles energised by a beam entering at `(r0, c0)` heading `d0`. Each time the
+    /// beam moves on a tile, `spread` is shown the directions it has passed through each tile in,
+    /// and where the front of the beam has got to.
+    fn beam(
+        &self,
+        r0: usize,
+        c0: usize,
+        d0: Dir4,
+        mut spread: impl FnMut(&Grid<DirSet>, &[(usize, usize, Dir4)]),
+    ) -> usize {
+        let mut visited = Grid::filled(self.tiles.width(), self.tiles.height(), DirSet::EMPTY);
+        let mut queue: VecDeque<(usize, usize, Dir4)> = VecDeque::new();
+
+        visited[(r0, c0)].insert(d0);
+        queue.push_back((r0, c0, d0));
+        while !queue.is_empty() {
+            spread(&visited, queue.make_contiguous());
+            for _ in 0..queue.len() {
+                let (r, c, d) = queue.pop_front().unwrap();
+                for (r1, c1, d1) in self.next(r, c, d) {
+                    // Don't get stuck in a loop.
+                    if visited[(r1, c1)].insert(d1) {
+                        queue.push_back((r1, c1, d1));
+                    }
+                }
+            }
+        }
+
+        visited.iter().filter(|(_, dirs)| !dirs.is_empty()).count()
+    }
+
+    fn part1(&self) -> usize {
+        self.beam(0, 0, Dir4::East, |_, _| {})
+    }
+
+    fn part2(&self) -> usize {
+        let (width, height) = (self.tiles.width(), self.tiles.height());
+        let mut res: Vec<usize> = vec![];
+        for i in 0..height {
+            res.push(self.beam(i, 0, Dir4::East, |_, _| {}));
+            res.push(self.beam(i, width - 1, Dir4::West, |_, _| {}));
+        }
+        for i in 0..width {
+            res.push(self.beam(0, i, Dir4::South, |_, _| {}));
+            res.push(self.beam(height - 1, i, Dir4::North, |_, _| {}));
+        }
+        res.into_iter().max().unwrap()
+    }
+}
+
+impl Solution for Day {
+    type Input<'a> = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::parse(input)
+    }
+
+    /// Number of tiles energized by a beam entering at the top-left corner.
+    fn part1(map: &Map) -> usize {
+        map.part1()
+    }
+
+    /// Largest number of tiles energized by a beam entering from any edge tile.
+    fn part2(map: &Map) -> usize {
+        map.part2()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(46, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(51, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
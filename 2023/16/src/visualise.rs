@@ -0,0 +1,52 @@
+//! Pictures of the beam spreading through the contraption.
+
+use crate::{Day, Map};
+use aoc_core::geom::{Dir4, DirSet};
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+use aoc_core::render::{Frame, Rgb};
+use aoc_core::Solution;
+
+/// Empty space, mirrors and splitters, energised tiles, and the front of the beam.
+const PALETTE: &[Rgb] = &[[12, 12, 20], [80, 90, 110], [200, 120, 30], [255, 240, 160]];
+
+fn draw(map: &Map, visited: &Grid<DirSet>, front: &[(usize, usize, Dir4)]) -> Frame {
+    let mut frame = Frame::new(map.tiles.width(), map.tiles.height(), PALETTE);
+    for ((y, x), &dirs) in visited.iter() {
+        if !dirs.is_empty() {
+            frame.set(x, y, 2);
+        } else if map.tiles[(y, x)] != '.' {
+            frame.set(x, y, 1);
+        }
+    }
+    for &(y, x, _) in front {
+        frame.set(x, y, 3);
+    }
+    frame
+}
+
+/// Draws the beam from the top-left corner, as in part 1, each time it moves on a tile.
+pub fn visualise(input: &str, frame: &mut dyn FnMut(&Frame)) -> Result<(), ParseError> {
+    let map = Day::parse(input)?;
+    map.beam(0, 0, Dir4::East, |visited, front| frame(&draw(&map, visited, front)));
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::visualise;
+    use aoc_core::example;
+
+    #[test]
+    fn test_visualise() {
+        let mut frames = vec![];
+        visualise(&example!(), &mut |f| frames.push(f.clone())).unwrap();
+        let last = frames.last().unwrap();
+        let energised = (0..10)
+            .flat_map(|y| (0..10).map(move |x| (x, y)))
+            .filter(|&(x, y)| last.get(x, y) >= 2)
+            .count();
+        assert_eq!(46, energised);
+        assert_eq!(3, frames[0].get(0, 0));
+    }
+}
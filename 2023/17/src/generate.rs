@@ -0,0 +1,36 @@
+//! Random city maps of any size, for stress testing.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A square map `size` blocks wide, each losing between 1 and 9 heat. It is at least five wide,
+/// so that the ultra crucible, which has to move four blocks before it can stop, can get across.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(5);
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(char::from(b'0' + rng.gen_range(1..=9)));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 20);
+        assert_eq!(input, generate(1, 20));
+        let map = Day::parse(&input).unwrap();
+        // Every block loses at least 1, and the shortest path from corner to corner crosses 38.
+        assert!(Day::part1(&map) >= 38);
+        assert!(Day::part2(&map) >= 38);
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_core::geom::Dir4;
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::search::{self, Explored};
+use aoc_core::Solution;
+
+mod generate;
+mod visualise;
+
+pub use generate::generate;
+pub use visualise::visualise;
+
+pub struct Day;
+
+/// Where the crucible is, and the direction it moved in to get there, or `None` at the start.
+type State = (Pos, Option<Dir4>);
+
+/// Finds the least heat loss from the top left to the bottom right, for a crucible that moves
+/// between `range_start + 1` and `range_end` blocks before turning. Each time the search moves
+/// on to a greater heat loss, and when it finishes, `searched` is shown what it has explored.
+fn path_len(
+    blocks: &Grid<u8>,
+    range_start: usize,
+    range_end: usize,
+    searched: impl FnMut(&Explored<State>),
+) -> usize {
+    let end = (blocks.height() - 1, blocks.width() - 1);
+
+    let moves = |&(pos0, dir0): &State| {
+        // Turn left or right, or go any way at the start.
+        let adj: Vec<Dir4> = match dir0 {
+            Some(dir) => vec![dir.turn_left(), dir.turn_right()],
+            None => Dir4::ALL.to_vec(),
+        };
+        let mut next = vec![];
+        for dir in adj {
+            let mut len = 0;
+            let mut pos = pos0;
+
+            for n in 0..range_end {
+                let Some(p) = blocks.step(pos, dir) else {
+                    break;
+                };
+                pos = p;
+
+                len += blocks[pos] as u64;
+
+                if n < range_start {
+                    continue;
+                }
+
+                next.push(((pos, Some(dir)), len));
+            }
+        }
+        next
+    };
+
+    let explored = search::dijkstra([((0, 0), None)], moves, |&(pos, _)| pos == end, searched);
+    explored.goal_cost().expect("No path found") as usize
+}
+
+impl Solution for Day {
+    /// The heat loss of each city block.
+    type Input<'a> = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(input, "0123456789", |c| c as u8 - b'0')
+    }
+
+    /// Least heat loss for a crucible that moves at most three blocks in a straight line.
+    fn part1(blocks: &Grid<u8>) -> usize {
+        path_len(blocks, 0, 3, |_| {})
+    }
+
+    /// Least heat loss for an ultra crucible that moves between four and ten blocks in a
+    /// straight line.
+    fn part2(blocks: &Grid<u8>) -> usize {
+        path_len(blocks, 3, 10, |_| {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::stats::{self, Stat};
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(102, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(94, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_stats() {
+        let blocks = Day::parse(&example!()).unwrap();
+        let (_, stats) = stats::collect(|| Day::part1(&blocks));
+        let Some(&Stat::Count(expanded)) = stats.get("states expanded") else {
+            panic!("No count of states expanded");
+        };
+        // Each of the 13x13 blocks is settled at most once for each direction it's entered from.
+        assert!((1..=13 * 13 * 4).contains(&expanded));
+        assert!(matches!(stats.get("max heap size"), Some(&Stat::Max(1..))));
+    }
+}
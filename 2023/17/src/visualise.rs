@@ -0,0 +1,62 @@
+//! Pictures of the search for the path with the least heat loss.
+
+use crate::{path_len, Day, State};
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+use aoc_core::render::{Frame, Rgb};
+use aoc_core::search::Explored;
+use aoc_core::Solution;
+
+/// The heat loss of each block from 1 to 9, then blocks the search has settled, and blocks on
+/// its frontier that it hasn't.
+const PALETTE: &[Rgb] = &[
+    [24, 12, 12],
+    [34, 14, 12],
+    [44, 16, 12],
+    [54, 18, 12],
+    [64, 20, 12],
+    [74, 22, 12],
+    [84, 24, 12],
+    [94, 26, 12],
+    [104, 28, 12],
+    [50, 110, 200],
+    [160, 240, 255],
+];
+
+fn draw(blocks: &Grid<u8>, explored: &Explored<State>) -> Frame {
+    let mut frame = Frame::new(blocks.width(), blocks.height(), PALETTE);
+    for ((y, x), &loss) in blocks.iter() {
+        frame.set(x, y, loss.clamp(1, 9) - 1);
+    }
+    for &((row, col), _) in explored.queued() {
+        frame.set(col, row, 10);
+    }
+    for (&((row, col), _), _) in explored.settled() {
+        frame.set(col, row, 9);
+    }
+    frame
+}
+
+/// Draws the search for part 1's path, each time it moves on to a greater heat loss.
+pub fn visualise(input: &str, frame: &mut dyn FnMut(&Frame)) -> Result<(), ParseError> {
+    let blocks = Day::parse(input)?;
+    path_len(&blocks, 0, 3, |explored| frame(&draw(&blocks, explored)));
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::visualise;
+    use aoc_core::example;
+
+    #[test]
+    fn test_visualise() {
+        let mut frames = vec![];
+        visualise(&example!(), &mut |f| frames.push(f.clone())).unwrap();
+        // The first frame is of the start, with the blocks it can reach queued.
+        assert_eq!(9, frames[0].get(0, 0));
+        assert_eq!([10, 10, 10], [1, 2, 3].map(|x| frames[0].get(x, 0)));
+        // By the end, the bottom right has been reached.
+        assert_eq!(9, frames.last().unwrap().get(12, 12));
+    }
+}
@@ -0,0 +1,303 @@
+use aoc_core::geom::Dir4;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, BinaryHeap};
+
+pub struct Day;
+
+/// A single dig instruction: a direction and a distance.
+type Step = (Dir4, i64);
+
+#[derive(Eq, PartialEq)]
+struct ByRow((i64, i64, i64));
+
+impl Ord for ByRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0 .0.cmp(&self.0 .0)
+    }
+}
+
+impl PartialOrd for ByRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Eq, PartialEq)]
+struct ByCol((i64, i64, i64));
+
+impl Ord for ByCol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0 .1.cmp(&other.0 .1)
+    }
+}
+
+impl PartialOrd for ByCol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parses a line of the dig plan, e.g. `R 6 (#70c710)`.
+fn parse_step(line: &mut Line) -> Result<(Step, Step), ParseError> {
+    let dir = Dir4::from_glyph(line.one_of("UDLR")?).unwrap();
+    line.tag(" ")?;
+    let len = line.number()?;
+    line.tag(" (#")?;
+    let start = *line;
+    let colour = line.take_while(|c| c.is_ascii_hexdigit(), "a hexadecimal colour")?;
+    let d = match (colour.len(), &colour[colour.len() - 1..]) {
+        (6, "0") => Dir4::East,
+        (6, "1") => Dir4::South,
+        (6, "2") => Dir4::West,
+        (6, "3") => Dir4::North,
+        _ => return Err(start.error("six hexadecimal digits ending in 0 to 3")),
+    };
+    line.tag(")")?;
+    let l = i64::from_str_radix(&colour[..5], 16).unwrap();
+    Ok(((dir, len), (d, l)))
+}
+
+fn fill(input: &[Step]) -> i64 {
+    // A list of vertical lines in the grid - (row0, col0, len).
+    let mut edges = BinaryHeap::new();
+    let (mut row, mut col) = (0, 0);
+    let (mut rowmin, mut rowmax) = (0, 0);
+
+    for &(dir, len) in input {
+        match dir {
+            Dir4::South => {
+                edges.push(ByRow((row, col, len)));
+                row += len;
+            }
+            Dir4::North => {
+                row -= len;
+                edges.push(ByRow((row, col, len)));
+            }
+            Dir4::West => {
+                col -= len;
+            }
+            Dir4::East => {
+                col += len;
+            }
+        }
+        rowmin = rowmin.min(row);
+        rowmax = rowmax.max(row);
+    }
+
+    let mut active = BTreeSet::new();
+    let mut count = 0;
+    // This can probably go faster by not iterating over _every_ row, but it works within
+    // reasonable time on my machine, so it's good enough for today.
+    for row in rowmin..=rowmax {
+        while let Some(ByRow((r, _, _))) = edges.peek() {
+            if *r == row {
+                active.insert(ByCol(edges.pop().unwrap().0));
+            } else {
+                break;
+            }
+        }
+        active.retain(|ByCol((r, _, l))| r + l >= row);
+
+        let mut inside = false;
+        let mut col0 = 0;
+        let mut prev_corner = None;
+        let mut prev_inside = false;
+        for ByCol((r, c, l)) in active.iter() {
+            if !inside {
+                col0 = *c;
+                prev_inside = inside;
+                inside = true;
+                if row == *r || row == r + l {
+                    prev_corner = Some((r, c, l));
+                }
+            } else if let Some((r0, _, l0)) = prev_corner {
+                prev_corner = None;
+                if row == *r0 && row == *r || row == (r0 + l0) && row == (r + l) {
+                    inside = prev_inside;
+                    if !inside {
+                        count += 1 + c - col0;
+                    }
+                } else {
+                    inside = !prev_inside;
+                    if !inside {
+                        count += 1 + c - col0;
+                    }
+                }
+            } else if row == *r || row == r + l {
+                prev_inside = inside;
+                prev_corner = Some((r, c, l));
+            } else {
+                inside = false;
+                count += 1 + c - col0;
+            }
+        }
+    }
+
+    count
+}
+
+impl Solution for Day {
+    /// Each line of the dig plan, read both as written and from its hexadecimal colour code.
+    type Input<'a> = Vec<(Step, Step)>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<(Step, Step)>, ParseError> {
+        parse::each_line(input, parse_step)
+    }
+
+    /// Cubic metres of lava held by the lagoon dug from the plan.
+    fn part1(plan: &Vec<(Step, Step)>) -> i64 {
+        fill(&plan.iter().map(|&(s, _)| s).collect::<Vec<_>>())
+    }
+
+    /// Cubic metres of lava held by the lagoon dug from the hexadecimal instructions.
+    fn part2(plan: &Vec<(Step, Step)>) -> i64 {
+        fill(&plan.iter().map(|&(_, s)| s).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{fill, Day, Step};
+    use aoc_core::geom::Dir4;
+    use aoc_core::{example, Solution};
+    use proptest::prelude::*;
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(62, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(952408144115, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+
+    /// The dig plan for the outline of a shape made of whole cells: the cells joined to the first
+    /// filled one, with any holes filled in. Each cell is `scale` tiles wide. `None` if the outline
+    /// touches itself at a corner, which a real dig plan never does.
+    ///
+    /// With a scale of 1, parts of the trench can run alongside each other, sealing off pockets of
+    /// ground that are outside the loop but that a flood fill can't reach. Real dig plans never do
+    /// that either, so the scale is at least 2.
+    fn outline(cells: &[Vec<bool>], scale: i64) -> Option<Vec<Step>> {
+        let filled = |r: i64, c: i64| {
+            let cell = cells.get(r as usize).and_then(|row| row.get(c as usize));
+            r >= 0 && c >= 0 && cell == Some(&true)
+        };
+        let first = (0..cells.len() as i64)
+            .flat_map(|r| (0..8).map(move |c| (r, c)))
+            .find(|&(r, c)| filled(r, c))?;
+        let mut shape = HashSet::from([first]);
+        let mut queue = VecDeque::from([first]);
+        while let Some((r, c)) = queue.pop_front() {
+            for p in [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)] {
+                if filled(p.0, p.1) && shape.insert(p) {
+                    queue.push_back(p);
+                }
+            }
+        }
+        // Fill the holes: anything the outside can't reach around the shape.
+        let mut outside = HashSet::from([(-1, -1)]);
+        let mut queue = VecDeque::from([(-1, -1)]);
+        while let Some((r, c)) = queue.pop_front() {
+            for p in [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)] {
+                let bounded = (-1..=cells.len() as i64).contains(&p.0) && (-1..=8).contains(&p.1);
+                if bounded && !shape.contains(&p) && outside.insert(p) {
+                    queue.push_back(p);
+                }
+            }
+        }
+        let shape: HashSet<_> = (0..cells.len() as i64)
+            .flat_map(|r| (0..8).map(move |c| (r, c)))
+            .filter(|p| !outside.contains(p))
+            .collect();
+
+        // Walk clockwise round the shape, along the corners of its cells.
+        let mut next = HashMap::new();
+        for &(r, c) in &shape {
+            let sides = [
+                ((r - 1, c), (r, c), (r, c + 1)),
+                ((r, c + 1), (r, c + 1), (r + 1, c + 1)),
+                ((r + 1, c), (r + 1, c + 1), (r + 1, c)),
+                ((r, c - 1), (r + 1, c), (r, c)),
+            ];
+            for (neighbour, from, to) in sides {
+                if !shape.contains(&neighbour) && next.insert(from, to).is_some() {
+                    return None;
+                }
+            }
+        }
+        let start = *next.keys().min().unwrap();
+        let mut steps: Vec<Step> = vec![];
+        let mut at = start;
+        loop {
+            let to = next[&at];
+            let dir = match (to.0 - at.0, to.1 - at.1) {
+                (0, 1) => Dir4::East,
+                (1, 0) => Dir4::South,
+                (0, -1) => Dir4::West,
+                _ => Dir4::North,
+            };
+            match steps.last_mut() {
+                Some((d, len)) if *d == dir => *len += scale,
+                _ => steps.push((dir, scale)),
+            }
+            at = to;
+            if at == start {
+                return Some(steps);
+            }
+        }
+    }
+
+    /// The lagoon's size found by digging out the trench tile by tile, then flood filling the
+    /// ground outside it.
+    fn flood_fill(plan: &[Step]) -> i64 {
+        let mut trench = HashSet::from([(0, 0)]);
+        let (mut r, mut c) = (0, 0);
+        for &(dir, len) in plan {
+            for _ in 0..len {
+                let step = dir.delta();
+                (r, c) = (r + step.y, c + step.x);
+                trench.insert((r, c));
+            }
+        }
+        let bounds = |coord: fn(&(i64, i64)) -> i64| {
+            let min = trench.iter().map(coord).min().unwrap();
+            let max = trench.iter().map(coord).max().unwrap();
+            min - 1..=max + 1
+        };
+        let (rows, cols) = (bounds(|p| p.0), bounds(|p| p.1));
+        let corner = (*rows.start(), *cols.start());
+        let mut outside = HashSet::from([corner]);
+        let mut queue = VecDeque::from([corner]);
+        while let Some((r, c)) = queue.pop_front() {
+            for p in [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)] {
+                let bounded = rows.contains(&p.0) && cols.contains(&p.1);
+                if bounded && !trench.contains(&p) && outside.insert(p) {
+                    queue.push_back(p);
+                }
+            }
+        }
+        let area = (rows.end() - rows.start() + 1) * (cols.end() - cols.start() + 1);
+        area - outside.len() as i64
+    }
+
+    fn plan() -> impl Strategy<Value = Vec<Step>> {
+        let cells = prop::collection::vec(prop::collection::vec(any::<bool>(), 8), 1..8);
+        (cells, 2..5_i64).prop_filter_map("outline touches itself", |(cells, scale)| {
+            outline(&cells, scale)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_fill_matches_flood_fill(plan in plan()) {
+            prop_assert_eq!(flood_fill(&plan), fill(&plan));
+        }
+    }
+}
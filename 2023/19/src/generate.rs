@@ -0,0 +1,77 @@
+//! Random workflows and parts of any size, for stress testing.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// `size` workflows forming a tree rooted at `in`, like the real inputs, each with between one
+/// and three tests before its default, followed by `size` parts with random ratings.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let mut names = vec!["in".to_string()];
+    let mut used: HashSet<String> = names.iter().cloned().collect();
+    // Names are two or three letters like the real ones, unless that's too few to go round.
+    let longest = (3..).find(|&l| 26_usize.pow(l) >= 2 * size).unwrap();
+    while names.len() < size {
+        let len = rng.gen_range(2..=longest);
+        let name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    // Workflows are given children in order, so every one but `in` has exactly one parent.
+    let mut next = 1;
+    let mut workflows = vec![];
+    for (i, name) in names.iter().enumerate() {
+        let tests = rng.gen_range(1..=3);
+        let mut targets = vec![];
+        for slot in 0..=tests {
+            let orphaned = next == i + 1 && slot == 0;
+            if next < size && (orphaned || rng.gen_bool(0.4)) {
+                targets.push(names[next].clone());
+                next += 1;
+            } else {
+                targets.push(if rng.gen_bool(0.5) { "A" } else { "R" }.to_string());
+            }
+        }
+        let default = targets.pop().unwrap();
+        let rules: Vec<String> = targets
+            .into_iter()
+            .map(|t| {
+                let rating = ['x', 'm', 'a', 's'][rng.gen_range(0..4)];
+                let op = if rng.gen_bool(0.5) { '<' } else { '>' };
+                format!("{}{}{}:{}", rating, op, rng.gen_range(1..=4000), t)
+            })
+            .collect();
+        workflows.push(format!("{}{{{},{}}}", name, rules.join(","), default));
+    }
+    workflows.shuffle(&mut rng);
+
+    let mut out = workflows.join("\n") + "\n\n";
+    for _ in 0..size {
+        let [x, m, a, s]: [u32; 4] = rng.gen::<[u32; 4]>().map(|r| r % 4000 + 1);
+        out += &format!("{{x={},m={},a={},s={}}}\n", x, m, a, s);
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 50);
+        assert_eq!(input, generate(1, 50));
+        let system = Day::parse(&input).unwrap();
+        assert_eq!(50, system.chains.len());
+        assert!(Day::part1(&system) <= 50 * 4 * 4000);
+        assert!(Day::part2(&system) <= 4000_i64.pow(4));
+    }
+}
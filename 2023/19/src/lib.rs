@@ -0,0 +1,353 @@
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use std::collections::HashMap;
+
+const ACCEPT: &str = "A";
+const REJECT: &str = "R";
+
+mod generate;
+
+pub use generate::generate;
+
+pub struct Day;
+
+/// The workflows, keyed by name, and the parts to be sorted.
+pub struct System {
+    chains: HashMap<String, Chain>,
+    parts: Vec<Part>,
+}
+
+impl System {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let blocks = parse::blocks(input);
+        let [chains, parts] = blocks.as_slice() else {
+            return Err(match blocks.get(2) {
+                Some(extra) => extra[0].error("end of input"),
+                None => ParseError::end_of_input(input.lines().count(), "workflows and parts"),
+            });
+        };
+        let chains = chains
+            .iter()
+            .map(|line| line.parse(Chain::parse).map(|c| (c.name.clone(), c)))
+            .collect::<Result<_, _>>()?;
+        let parts = parts
+            .iter()
+            .map(|line| line.parse(Part::parse))
+            .collect::<Result<_, _>>()?;
+        Ok(System { chains, parts })
+    }
+}
+
+struct Chain {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Chain {
+    /// Parses a workflow, e.g. `px{a<2006:qkq,m>2090:A,rfg}`.
+    fn parse(line: &mut Line) -> Result<Self, ParseError> {
+        let name = line.word()?.to_string();
+        line.tag("{")?;
+        let rules = line.list(",", Rule::parse)?;
+        line.tag("}")?;
+        Ok(Chain { name, rules })
+    }
+
+    fn apply(&self, part: &Part) -> String {
+        self.rules
+            .iter()
+            .map(|r| r.apply(part))
+            .find(|r| r.is_some())
+            .unwrap()
+            .unwrap()
+    }
+
+    fn map(&self, range: &PartRange) -> Vec<(String, PartRange)> {
+        let mut ret = vec![];
+        let mut next = range.clone();
+        for rule in self.rules.iter() {
+            let (dest, matched, unmatched) = rule.map(&next);
+            if let Some(r) = matched {
+                ret.push((dest.clone(), r));
+            }
+            if let Some(r) = unmatched {
+                next = r;
+            } else {
+                break;
+            }
+        }
+        ret
+    }
+}
+
+struct Rule {
+    test: Option<Test>,
+    destination: String,
+}
+
+struct Test {
+    subject: Param,
+    predicate: Pred,
+}
+
+impl Rule {
+    /// Parses a rule, which is either a test and a destination (`a<2006:qkq`) or just a
+    /// destination (`rfg`).
+    fn parse(line: &mut Line) -> Result<Self, ParseError> {
+        let start = *line;
+        let word = line.word()?;
+        let test = match line.one_of("<>") {
+            Ok(op) => {
+                let subject = match word {
+                    "x" => Param::X,
+                    "m" => Param::M,
+                    "a" => Param::A,
+                    "s" => Param::S,
+                    _ => return Err(start.error("one of x, m, a or s")),
+                };
+                let val = line.number()?;
+                let predicate = match op {
+                    '>' => Pred::GreaterThan(val),
+                    _ => Pred::LessThan(val),
+                };
+                line.tag(":")?;
+                Some(Test { subject, predicate })
+            }
+            Err(_) => None,
+        };
+        let destination = match test {
+            Some(_) => line.word()?.to_string(),
+            None => word.to_string(),
+        };
+        Ok(Rule { test, destination })
+    }
+
+    fn apply(&self, part: &Part) -> Option<String> {
+        if let Some(Test { subject, predicate }) = &self.test {
+            if predicate.matches(part.get(subject)) {
+                Some(self.destination.clone())
+            } else {
+                None
+            }
+        } else {
+            Some(self.destination.clone())
+        }
+    }
+
+    fn map(&self, range: &PartRange) -> (String, Option<PartRange>, Option<PartRange>) {
+        if let Some(Test { subject, predicate }) = &self.test {
+            let (matched, non_matched) = predicate.map(range.get(subject));
+            (
+                self.destination.clone(),
+                matched.map(|r| range.with(subject, r)),
+                non_matched.map(|r| range.with(subject, r)),
+            )
+        } else {
+            (self.destination.clone(), Some(range.clone()), None)
+        }
+    }
+}
+
+struct Part {
+    x: i64,
+    m: i64,
+    a: i64,
+    s: i64,
+}
+
+impl Part {
+    /// Parses a part's ratings, e.g. `{x=787,m=2655,a=1222,s=2876}`.
+    fn parse(line: &mut Line) -> Result<Self, ParseError> {
+        line.tag("{x=")?;
+        let x = line.number()?;
+        line.tag(",m=")?;
+        let m = line.number()?;
+        line.tag(",a=")?;
+        let a = line.number()?;
+        line.tag(",s=")?;
+        let s = line.number()?;
+        line.tag("}")?;
+        Ok(Part { x, m, a, s })
+    }
+
+    fn get(&self, p: &Param) -> i64 {
+        match p {
+            Param::X => self.x,
+            Param::M => self.m,
+            Param::A => self.a,
+            Param::S => self.s,
+        }
+    }
+
+    fn sum(&self) -> i64 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Clone)]
+struct PartRange {
+    x: (i64, i64),
+    m: (i64, i64),
+    a: (i64, i64),
+    s: (i64, i64),
+}
+
+impl PartRange {
+    fn new() -> Self {
+        PartRange {
+            x: (1, 4000),
+            m: (1, 4000),
+            a: (1, 4000),
+            s: (1, 4000),
+        }
+    }
+
+    fn get(&self, p: &Param) -> (i64, i64) {
+        match p {
+            Param::X => self.x,
+            Param::M => self.m,
+            Param::A => self.a,
+            Param::S => self.s,
+        }
+    }
+
+    fn with(&self, p: &Param, r: (i64, i64)) -> Self {
+        let mut other = self.clone();
+        match p {
+            Param::X => other.x = r,
+            Param::M => other.m = r,
+            Param::A => other.a = r,
+            Param::S => other.s = r,
+        }
+        other
+    }
+
+    fn product(&self) -> i64 {
+        (1 + self.x.1 - self.x.0)
+            * (1 + self.m.1 - self.m.0)
+            * (1 + self.a.1 - self.a.0)
+            * (1 + self.s.1 - self.s.0)
+    }
+}
+
+enum Param {
+    X,
+    M,
+    A,
+    S,
+}
+
+/// An inclusive range of rating values.
+type Range = (i64, i64);
+
+enum Pred {
+    GreaterThan(i64),
+    LessThan(i64),
+}
+
+impl Pred {
+    fn matches(&self, val: i64) -> bool {
+        match self {
+            Pred::GreaterThan(t) => val > *t,
+            Pred::LessThan(t) => val < *t,
+        }
+    }
+
+    fn map(&self, (a, b): Range) -> (Option<Range>, Option<Range>) {
+        match self {
+            Pred::GreaterThan(t) => {
+                if *t >= a && *t < b {
+                    (Some((t + 1, b)), Some((a, *t)))
+                } else if *t >= b {
+                    (None, Some((a, b)))
+                } else {
+                    // t < a
+                    (Some((a, b)), None)
+                }
+            }
+            Pred::LessThan(t) => {
+                if *t > a && *t <= b {
+                    (Some((a, *t - 1)), Some((*t, b)))
+                } else if *t > b {
+                    (Some((a, b)), None)
+                } else {
+                    // t <= a
+                    (None, Some((a, b)))
+                }
+            }
+        }
+    }
+}
+
+impl Solution for Day {
+    type Input<'a> = System;
+    type Part1 = i64;
+    type Part2 = i64;
+    const SHAPE: Shape = Shape::Blocks;
+
+    fn parse(input: &str) -> Result<System, ParseError> {
+        System::parse(input)
+    }
+
+    /// Sum of the ratings of every accepted part.
+    fn part1(System { chains, parts }: &System) -> i64 {
+        let mut total = 0;
+        for part in parts {
+            let mut ch = "in".to_string();
+            while ch != REJECT && ch != ACCEPT {
+                ch = chains.get(&ch).unwrap().apply(part);
+            }
+            if ch == ACCEPT {
+                total += part.sum();
+            }
+        }
+        total
+    }
+
+    /// Number of distinct rating combinations that will be accepted.
+    fn part2(System { chains, .. }: &System) -> i64 {
+        let mut total = 0;
+        let mut ranges = vec![("in".to_string(), PartRange::new())];
+        while !ranges.is_empty() {
+            ranges = ranges
+                .iter()
+                .flat_map(|(ch, r)| chains.get(ch).unwrap().map(r))
+                .collect();
+            total += ranges.iter().filter(|(ch, _)| ch == ACCEPT).map(|(_, r)| r.product()).sum::<i64>();
+            ranges.retain(|(ch, _)| ch != ACCEPT && ch != REJECT);
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(19114, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(167409079868000, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_bad_part() {
+        let input = example!().replace("{x=2036,m=264", "{x=2036;m=264");
+        assert_eq!(
+            "line 15, column 8: expected \",m=\", found ';'",
+            Day::parse(&input).err().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_crlf() {
+        let input = example!().replace('\n', "\r\n");
+        assert_eq!(19114, Day::part1(&Day::parse(&input).unwrap()));
+    }
+}
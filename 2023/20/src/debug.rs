@@ -0,0 +1,133 @@
+//! The pulses a button press sets off, one at a time, for the step debugger.
+
+use crate::{process, Day, Memory};
+use aoc_core::debug::{State, Value};
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
+use std::fmt::Write;
+
+/// A pulse on its way to a module.
+struct Pulse<'a> {
+    press: i64,
+    from: &'a str,
+    to: &'a str,
+    high: bool,
+    memory: &'a Memory<'a>,
+}
+
+impl Pulse<'_> {
+    fn level(&self) -> &'static str {
+        if self.high {
+            "high"
+        } else {
+            "low"
+        }
+    }
+}
+
+impl State for Pulse<'_> {
+    fn unit(&self) -> &'static str {
+        "press"
+    }
+
+    fn describe(&self) -> String {
+        format!("press {}: {} -{}-> {}", self.press, self.from, self.level(), self.to)
+    }
+
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("press", self.press.into()),
+            ("from", self.from.into()),
+            ("to", self.to.into()),
+            ("pulse", self.level().into()),
+        ]
+    }
+
+    fn inspect(&self) -> String {
+        let mut out = format!("{}\n", self.describe());
+        let mut on: Vec<_> = self.memory.flip_flops.iter().filter(|f| *f.1).map(|f| *f.0).collect();
+        on.sort();
+        let on = match on.is_empty() {
+            true => "none".to_string(),
+            false => on.join(" "),
+        };
+        let _ = writeln!(out, "flip-flops on: {} (of {})", on, self.memory.flip_flops.len());
+        let mut collectors: Vec<_> = self.memory.collectors.iter().collect();
+        collectors.sort_by_key(|c| c.0);
+        for (name, inputs) in collectors {
+            let mut inputs: Vec<_> = inputs.iter().collect();
+            inputs.sort();
+            let inputs: Vec<_> = inputs
+                .iter()
+                .map(|(input, &high)| format!("{}={}", input, if high { "high" } else { "low" }))
+                .collect();
+            let _ = writeln!(out, "&{} remembers {}", name, inputs.join(" "));
+        }
+        out.trim_end().to_string()
+    }
+}
+
+/// Presses the button as many times as the part does (1000 times, or 10,000 for part 2), stopping
+/// before each pulse is received.
+pub fn debug(
+    input: &str,
+    part: u8,
+    stepped: &mut dyn FnMut(&dyn State) -> bool,
+) -> Result<(), ParseError> {
+    let config = Day::parse(input)?;
+    let presses = if part == 1 { 1000 } else { 10_000 };
+    let mut running = true;
+    process(&config, presses, |press, from, to, high, memory| {
+        if running {
+            running = stepped(&Pulse {
+                press,
+                from,
+                to,
+                high,
+                memory,
+            });
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::debug;
+    use aoc_core::example;
+
+    #[test]
+    fn test_debug() {
+        let mut seen = vec![];
+        debug(&example!("example2.txt"), 1, &mut |s| {
+            seen.push(s.describe());
+            if seen.len() < 9 {
+                return true;
+            }
+            // The second press, once the first has settled.
+            assert_eq!(
+                "press 2: button -low-> broadcaster\n\
+                 flip-flops on: a b (of 2)\n\
+                 &con remembers a=high b=high\n\
+                 &inv remembers a=high",
+                s.inspect()
+            );
+            false
+        })
+        .unwrap();
+        assert_eq!(
+            vec![
+                "press 1: button -low-> broadcaster",
+                "press 1: broadcaster -low-> a",
+                "press 1: a -high-> inv",
+                "press 1: a -high-> con",
+                "press 1: inv -low-> b",
+                "press 1: con -high-> output",
+                "press 1: b -high-> con",
+                "press 1: con -low-> output",
+                "press 2: button -low-> broadcaster",
+            ],
+            seen[..9]
+        );
+    }
+}
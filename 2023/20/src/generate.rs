@@ -0,0 +1,82 @@
+//! Random module configurations of up to five counters, for stress testing.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// How many flip-flops each counter has, as in the real inputs.
+const BITS: usize = 12;
+
+/// The most counters there can be while the LCM of their periods, each under `1 << BITS`, still
+/// fits in the `i64` part 2 answers with.
+const MAX_COUNTERS: usize = 5;
+
+/// Modules laid out like the real inputs, with `size` 12-bit counters in place of the usual four,
+/// up to five.
+///
+/// The broadcaster starts each counter, which is a chain of flip-flops counting button presses in
+/// binary. A collector watches the flip-flops whose bits are set in the counter's (random, odd)
+/// period, and when they all are it resets the counter and pulses an inverter. The inverters all
+/// feed the collector in front of `rx`, so `rx` gets its low pulse after the LCM of the periods.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.clamp(1, MAX_COUNTERS);
+    let mut used: HashSet<String> = ["rx".to_string()].into();
+    let count = size * (BITS + 2) + 1;
+    // Names are two letters like the real ones, unless that's too few to go round.
+    let len = (2..).find(|&l| 26_usize.pow(l) >= 2 * count).unwrap();
+    let mut names = vec![];
+    while names.len() < count {
+        let name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    let last = names.pop().unwrap();
+
+    let mut lines = vec![];
+    let mut starts = vec![];
+    for counter in names.chunks(BITS + 2) {
+        let (bits, collector, inverter) = (&counter[..BITS], &counter[BITS], &counter[BITS + 1]);
+        let period: u32 = rng.gen_range(1 << (BITS - 1)..1 << BITS) | 1;
+        let mut resets = vec![bits[0].clone()];
+        for (i, bit) in bits.iter().enumerate() {
+            let mut targets = vec![];
+            if let Some(next) = bits.get(i + 1) {
+                targets.push(next.clone());
+            }
+            if period & (1 << i) != 0 {
+                targets.push(collector.clone());
+            } else {
+                resets.push(bit.clone());
+            }
+            lines.push(format!("%{} -> {}", bit, targets.join(", ")));
+        }
+        resets.push(inverter.clone());
+        lines.push(format!("&{} -> {}", collector, resets.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, last));
+        starts.push(bits[0].clone());
+    }
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.push(format!("&{} -> rx", last));
+    lines.shuffle(&mut rng);
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 3);
+        assert_eq!(input, generate(1, 3));
+        let config = Day::parse(&input).unwrap();
+        assert_eq!(3 * 14 + 2, config.len());
+        Day::part1(&config);
+        assert!(Day::part2(&config) >= 1 << 11);
+    }
+}
@@ -0,0 +1,196 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+mod debug;
+mod generate;
+
+pub use debug::debug;
+pub use generate::generate;
+
+pub struct Day;
+
+#[derive(PartialEq)]
+pub enum NodeType {
+    Broadcaster,
+    FlipFlop,
+    Collector,
+    Other,
+}
+
+/// The type of each module and the names of the modules it sends pulses to, keyed by name.
+pub type Config<'a> = HashMap<&'a str, (NodeType, Vec<&'a str>)>;
+
+/// Parses a module, e.g. `%a -> inv, con`.
+fn parse_node<'a>(line: &mut Line<'a>) -> Result<(&'a str, (NodeType, Vec<&'a str>)), ParseError> {
+    let typ = if line.eat("%") {
+        NodeType::FlipFlop
+    } else if line.eat("&") {
+        NodeType::Collector
+    } else {
+        NodeType::Other
+    };
+    let name = line.word()?;
+    let typ = match typ {
+        NodeType::Other if name == "broadcaster" => NodeType::Broadcaster,
+        typ => typ,
+    };
+    line.tag(" -> ")?;
+    let targets = line.list(", ", |l| l.word())?;
+    Ok((name, (typ, targets)))
+}
+
+fn parse_config(input: &str) -> Result<Config<'_>, ParseError> {
+    Ok(parse::each_line(input, parse_node)?.into_iter().collect())
+}
+
+/// What the modules remember: whether each flip-flop is on, and the last pulse each collector
+/// received from each of its inputs.
+pub struct Memory<'a> {
+    flip_flops: HashMap<&'a str, bool>,
+    collectors: HashMap<&'a str, HashMap<&'a str, bool>>,
+}
+
+impl<'a> Memory<'a> {
+    /// Every flip-flop off, and every collector remembering a low pulse from each input.
+    fn new(config: &Config<'a>) -> Self {
+        let mut collectors: HashMap<&str, HashMap<&str, bool>> = config
+            .iter()
+            .filter(|(_, (t, _))| *t == NodeType::Collector)
+            .map(|(&name, _)| (name, HashMap::new()))
+            .collect();
+        for (&name, (_, targets)) in config.iter() {
+            for target in targets {
+                if let Some(inputs) = collectors.get_mut(target) {
+                    inputs.insert(name, false);
+                }
+            }
+        }
+        let flip_flops = config
+            .iter()
+            .filter(|(_, (typ, _))| *typ == NodeType::FlipFlop)
+            .map(|(&name, _)| (name, false))
+            .collect();
+        Memory {
+            flip_flops,
+            collectors,
+        }
+    }
+}
+
+/// Presses the button `iterations` times, calling `on_pulse` with the press number (starting at 1),
+/// sender, receiver and level of every pulse sent, and what the modules remember, before the pulse
+/// is received. Returns the product of the number of low and high pulses.
+fn process<'a, F>(config: &Config<'a>, iterations: i64, mut on_pulse: F) -> i64
+where
+    F: FnMut(i64, &str, &str, bool, &Memory),
+{
+    let mut memory = Memory::new(config);
+    let mut lows = 0;
+    let mut highs = 0;
+    let mut queue: VecDeque<(&'a str, &'a str, bool)> = VecDeque::new();
+    for i in 0..iterations {
+        queue.push_back(("button", "broadcaster", false));
+        while let Some((sender, name, pulse)) = queue.pop_front() {
+            on_pulse(i + 1, sender, name, pulse, &memory);
+            if !pulse {
+                lows += 1;
+            } else {
+                highs += 1;
+            }
+            if let Some((name, (typ, targets))) = config.get_key_value(name) {
+                match typ {
+                    NodeType::Broadcaster => {
+                        for t in targets {
+                            queue.push_back((name, t, pulse));
+                        }
+                    },
+                    NodeType::FlipFlop => {
+                        if !pulse {
+                            let state = memory.flip_flops.get_mut(name).unwrap();
+                            *state = !*state;
+                            for t in targets {
+                                queue.push_back((name, t, *state));
+                            }
+                        }
+                    },
+                    NodeType::Collector => {
+                        let inputs = memory.collectors.get_mut(name).unwrap();
+                        inputs.insert(sender, pulse);
+                        let all_on = inputs.values().all(|b| *b);
+                        for t in targets {
+                            queue.push_back((name, t, !all_on));
+                        }
+                    },
+                    NodeType::Other => {}
+                }
+            }
+        }
+    }
+    lows * highs
+}
+
+fn lcm(a: i64, b: i64) -> i64 {
+    let (mut gcd, mut tmp) = (a, b);
+    while tmp != 0 {
+        (gcd, tmp) = (tmp, gcd % tmp);
+    }
+    (a / gcd) * b
+}
+
+impl Solution for Day {
+    type Input<'a> = Config<'a>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Config<'_>, ParseError> {
+        parse_config(input)
+    }
+
+    /// Product of the number of low and high pulses sent after 1000 button presses.
+    fn part1(config: &Config) -> i64 {
+        process(config, 1000, |_, _, _, _, _| {})
+    }
+
+    /// Fewest button presses needed to deliver a single low pulse to `rx`.
+    ///
+    /// `rx` is fed by a single collector, which only sends a low pulse once all of its inputs
+    /// have most recently sent it a high pulse. Each of those inputs sends a high pulse on a
+    /// regular cycle, so the answer is the LCM of the cycle lengths.
+    fn part2(config: &Config) -> i64 {
+        let senders = |target: &str| {
+            config
+                .iter()
+                .filter(|(_, (_, targets))| targets.contains(&target))
+                .map(|(&name, _)| name)
+                .collect::<Vec<_>>()
+        };
+        let [collector] = senders("rx")[..] else {
+            unreachable!("Expected a single input to rx")
+        };
+        let inputs = senders(collector);
+
+        let mut cycles: HashMap<String, i64> = HashMap::new();
+        process(config, 10_000, |i, sender, name, pulse, _| {
+            if name == collector && pulse && !cycles.contains_key(sender) {
+                cycles.insert(sender.to_string(), i);
+            }
+        });
+        assert_eq!(inputs.len(), cycles.len(), "No cycle found for some inputs");
+
+        cycles.into_values().reduce(lcm).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(32000000, Day::part1(&Day::parse(&example!("example1.txt")).unwrap()));
+        assert_eq!(11687500, Day::part1(&Day::parse(&example!("example2.txt")).unwrap()));
+    }
+}
@@ -0,0 +1,44 @@
+//! Random gardens of any size, for stress testing.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A square garden `size` plots wide (rounded up to an odd number), with the start in the middle
+/// and roughly one plot in eight a rock. Like the real inputs, the edges and the row and column
+/// through the start are clear, which is what lets the reachable area grow quadratically.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(3) | 1;
+    let mid = size / 2;
+    let mut out = String::new();
+    for r in 0..size {
+        for c in 0..size {
+            let clear = r == 0 || c == 0 || r == size - 1 || c == size - 1 || r == mid || c == mid;
+            out.push(match (r, c) {
+                (r, c) if r == mid && c == mid => 'S',
+                _ if !clear && rng.gen_range(0..8) == 0 => '#',
+                _ => '.',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 20);
+        assert_eq!(input, generate(1, 20));
+        assert_eq!(21, input.lines().count());
+        let map = Day::parse(&input).unwrap();
+        // After an even number of steps, at most every other plot within reach is occupied.
+        assert!(Day::part1(&map) <= 65 * 65);
+        assert!(Day::part2(&map) > 0);
+    }
+}
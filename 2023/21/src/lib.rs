@@ -0,0 +1,159 @@
+use aoc_core::grid::Grid;
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::Solution;
+use std::collections::HashSet;
+
+mod generate;
+mod visualise;
+
+pub use generate::generate;
+pub use visualise::visualise;
+
+pub struct Day;
+
+#[derive(Clone)]
+pub struct Map {
+    walls: Grid<bool>,
+    width: i64,
+    height: i64,
+    positions: HashSet<(i64, i64)>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        let map = Grid::parse(input, ".#S", |c| c)?;
+        let Some((row, col)) = map.find(&'S') else {
+            return Err(ParseError::end_of_input(map.height(), "a starting position 'S'"));
+        };
+        Ok(Map {
+            walls: map.map(|&c| c == '#'),
+            width: map.width() as i64,
+            height: map.height() as i64,
+            positions: HashSet::from([(row as i64, col as i64)]),
+        })
+    }
+
+    fn step(&mut self, iterations: i64) {
+        for _ in 0..iterations {
+            let mut new_positions = HashSet::new();
+            for &(r, c) in self.positions.iter() {
+                if new_positions.contains(&(r, c)) || *self.walls.get_wrapped((r, c)) {
+                    continue;
+                }
+                for (dr, dc) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                    let (r1, c1) = (r + dr, c + dc);
+                    if !new_positions.contains(&(r1, c1)) && !self.walls.get_wrapped((r1, c1)) {
+                        new_positions.insert((r1, c1));
+                    }
+                }
+            }
+            self.positions = new_positions;
+        }
+    }
+
+    fn num_reachable(&self) -> i64 {
+        self.positions.len() as i64
+    }
+}
+
+fn reachable(map: &Map, iterations: i64) -> i64 {
+    let mut map = map.clone();
+    map.step(iterations);
+    map.num_reachable()
+}
+
+fn solve(map: &Map, goal: i64) -> i64 {
+    let mut map = map.clone();
+    assert_eq!(map.width, map.height);
+    let dim = map.width;
+    map.step(goal % dim);
+    let a0 = map.num_reachable();
+    map.step(dim);
+    let a1 = map.num_reachable();
+    map.step(dim);
+    let a2 = map.num_reachable();
+    let b0 = a1 - a0;
+    let b1 = a2 - a1;
+    let n = goal / dim;
+    a0 + b0 * n + (n * (n - 1) / 2) * (b1 - b0)
+}
+
+impl Solution for Day {
+    type Input<'a> = Map;
+    type Part1 = i64;
+    type Part2 = i64;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::parse(input)
+    }
+
+    /// Garden plots reachable in exactly 64 steps.
+    fn part1(map: &Map) -> i64 {
+        reachable(map, 64)
+    }
+
+    /// Garden plots reachable in exactly 26501365 steps on the infinitely repeating map.
+    fn part2(map: &Map) -> i64 {
+        solve(map, 26501365)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{reachable, solve, Map};
+    use aoc_core::example;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(16, reachable(&Map::parse(&example!()).unwrap(), 6));
+    }
+
+    #[test]
+    fn test_part2() {
+        let map = Map::parse(&example!()).unwrap();
+        assert_eq!(16, reachable(&map, 6));
+        assert_eq!(50, reachable(&map, 10));
+        assert_eq!(1594, reachable(&map, 50));
+    }
+
+    /// A square garden with the start in the middle, and the rocks given row by row, except that
+    /// like the real inputs the edges and the row and column through the start are clear.
+    fn garden(rocks: &[Vec<bool>]) -> Map {
+        let size = rocks.len();
+        let mid = size / 2;
+        let mut text = String::new();
+        for (r, row) in rocks.iter().enumerate() {
+            for (c, &rock) in row.iter().enumerate() {
+                let edge = r == 0 || c == 0 || r == size - 1 || c == size - 1;
+                let clear = edge || r == mid || c == mid;
+                text.push(match rock {
+                    _ if r == mid && c == mid => 'S',
+                    true if !clear => '#',
+                    _ => '.',
+                });
+            }
+            text.push('\n');
+        }
+        Map::parse(&text).unwrap()
+    }
+
+    fn rocks() -> impl Strategy<Value = Vec<Vec<bool>>> {
+        (2..4_usize).prop_flat_map(|half| {
+            let size = 2 * half + 1;
+            let row = prop::collection::vec(prop::bool::weighted(0.2), size);
+            prop::collection::vec(row, size)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_solve_matches_reachable(rocks in rocks(), repeats in 3..5_i64) {
+            let map = garden(&rocks);
+            let goal = repeats * map.width + map.width / 2;
+            prop_assert_eq!(reachable(&map, goal), solve(&map, goal));
+        }
+    }
+}
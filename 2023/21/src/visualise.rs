@@ -0,0 +1,71 @@
+//! Pictures of the reachable garden plots spreading across the repeating map.
+
+use crate::Day;
+use aoc_core::parse::ParseError;
+use aoc_core::render::{Frame, Rgb};
+use aoc_core::Solution;
+
+/// Garden plots, the plots of the starting copy of the map, rocks, and reachable plots.
+const PALETTE: &[Rgb] = &[[30, 60, 25], [45, 85, 35], [90, 90, 90], [240, 230, 120]];
+
+/// How many copies of the map to draw across and down, with the starting copy in the middle.
+const TILES: i64 = 5;
+
+/// Draws the plots reachable at the start and after each step that part 2 simulates, which is
+/// as far as the middle five copies of the map reach.
+pub fn visualise(input: &str, frame: &mut dyn FnMut(&Frame)) -> Result<(), ParseError> {
+    let mut map = Day::parse(input)?;
+    let (width, height) = (map.width, map.height);
+    let (x0, y0) = (-(TILES / 2) * width, -(TILES / 2) * height);
+
+    let mut background = Frame::new((TILES * width) as usize, (TILES * height) as usize, PALETTE);
+    for y in 0..TILES * height {
+        for x in 0..TILES * width {
+            let (r, c) = (y + y0, x + x0);
+            let colour = if *map.walls.get_wrapped((r, c)) {
+                2
+            } else if (0..height).contains(&r) && (0..width).contains(&c) {
+                1
+            } else {
+                0
+            };
+            background.set(x as usize, y as usize, colour);
+        }
+    }
+
+    let steps = 26501365 % width + 2 * width;
+    for step in 0..=steps {
+        if step > 0 {
+            map.step(1);
+        }
+        let mut f = background.clone();
+        for &(r, c) in &map.positions {
+            let (x, y) = (c - x0, r - y0);
+            if (0..TILES * width).contains(&x) && (0..TILES * height).contains(&y) {
+                f.set(x as usize, y as usize, 3);
+            }
+        }
+        frame(&f);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::visualise;
+    use aoc_core::example;
+
+    #[test]
+    fn test_visualise() {
+        let mut frames = vec![];
+        visualise(&example!(), &mut |f| frames.push(f.clone())).unwrap();
+        // The start, and a frame for each of the 22 steps.
+        assert_eq!(23, frames.len());
+        assert_eq!((55, 55), (frames[0].width(), frames[0].height()));
+        assert_eq!(3, frames[0].get(27, 27));
+        assert_eq!(2, frames[0].get(1, 2 * 11 + 2));
+        let reached = |i: usize| frames[i].rows().flatten().filter(|&&c| c == 3).count();
+        assert_eq!(16, reached(6));
+        assert_eq!(50, reached(10));
+    }
+}
@@ -0,0 +1,56 @@
+//! Random snapshots of falling bricks of any size, for stress testing.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// The width and depth of the area the bricks fall into, as in the real inputs.
+const AREA: i32 = 10;
+
+/// `size` bricks (at least one), each a straight line of one to four cubes along a random axis,
+/// scattered through the air above a 10x10 area without overlapping, in no particular order.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let height = size as i32 / 3 + 10;
+    let mut filled = HashSet::new();
+    let mut out = String::new();
+    let mut placed = 0;
+    while placed < size {
+        let len = rng.gen_range(0..4);
+        let (dx, dy, dz) = match rng.gen_range(0..3) {
+            0 => (len, 0, 0),
+            1 => (0, len, 0),
+            _ => (0, 0, len),
+        };
+        let x = rng.gen_range(0..AREA - dx);
+        let y = rng.gen_range(0..AREA - dy);
+        let z = rng.gen_range(1..=height);
+        let cubes: Vec<_> = (0..=len)
+            .map(|i| (x + dx.min(i), y + dy.min(i), z + dz.min(i)))
+            .collect();
+        if cubes.iter().any(|c| filled.contains(c)) {
+            continue;
+        }
+        filled.extend(cubes);
+        out += &format!("{},{},{}~{},{},{}\n", x, y, z, x + dx, y + dy, z + dz);
+        placed += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 100);
+        assert_eq!(input, generate(1, 100));
+        let stack = Day::parse(&input).unwrap();
+        assert!(Day::part1(&stack) <= 100);
+        assert!(Day::part2(&stack) <= 100 * 99);
+    }
+}
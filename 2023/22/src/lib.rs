@@ -1,9 +1,15 @@
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::Solution;
 use core::cmp::Reverse;
-use once_cell::sync::Lazy;
-use regex::Regex;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 
-static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\d+),(\d+),(\d+)~(\d+),(\d+),(\d+)").unwrap());
+mod generate;
+mod visualise;
+
+pub use generate::generate;
+pub use visualise::visualise;
+
+pub struct Day;
 
 #[derive(Hash, Eq, PartialEq, Clone)]
 struct Block {
@@ -20,14 +26,22 @@ struct Brick {
 }
 
 impl<'a> Brick {
-    fn parse(s: &str) -> Brick {
-        let c = RE
-            .captures(s)
-            .unwrap()
-            .iter()
-            .skip(1)
-            .map(|m| m.unwrap().as_str().parse::<i32>().unwrap())
-            .collect::<Vec<_>>();
+    /// Parses the coordinates of both ends of a brick, e.g. `1,0,1~1,2,1`. Bricks are straight
+    /// lines of cubes, so only one coordinate may differ between the ends.
+    fn parse(line: &mut Line) -> Result<Brick, ParseError> {
+        let coordinate = |l: &mut Line| l.number::<u16>().map(i32::from);
+        let mut c = line.list(",", coordinate)?;
+        if c.len() != 3 {
+            return Err(line.error("three coordinates"));
+        }
+        line.tag("~")?;
+        c.extend(line.list(",", coordinate)?);
+        if c.len() != 6 {
+            return Err(line.error("three coordinates"));
+        }
+        if (0..3).filter(|&i| c[i] != c[i + 3]).count() > 1 {
+            return Err(line.error_at(0, "a straight brick"));
+        }
         let x0 = c[0].min(c[3]);
         let x1 = c[0].max(c[3]);
         let y0 = c[1].min(c[4]);
@@ -43,7 +57,7 @@ impl<'a> Brick {
                 z: z0 + (i * dz / n),
             })
             .collect();
-        Brick { blocks, z0, z1 }
+        Ok(Brick { blocks, z0, z1 })
     }
 
     fn bottom(&'a self) -> Vec<&'a Block> {
@@ -63,21 +77,20 @@ impl<'a> Brick {
     }
 }
 
-struct Stack {
+pub struct Stack {
     count: usize,
     above: HashMap<usize, HashSet<usize>>,
     below: HashMap<usize, HashSet<usize>>,
 }
 
 impl Stack {
-    fn create(input: &str) -> Stack {
-        let mut bricks = input
-            .trim()
-            .split("\n")
-            .map(|s| Brick::parse(s))
-            .collect::<Vec<_>>();
+    /// Lets the bricks fall and works out which support which. `fell` is shown the bricks, lowest
+    /// first, and how many of them have settled, before any fall and again as each one lands.
+    fn create(input: &str, mut fell: impl FnMut(&[Brick], usize)) -> Result<Stack, ParseError> {
+        let mut bricks = parse::each_line(input, Brick::parse)?;
         let mut topo: HashMap<(i32, i32), i32> = HashMap::new();
         bricks.sort_by_key(|b| b.z0);
+        fell(&bricks, 0);
         for i in 0..bricks.len() {
             let dz = bricks[i]
                 .bottom()
@@ -89,6 +102,7 @@ impl Stack {
             bricks[i].blocks.iter().for_each(|b| {
                 topo.insert((b.x, b.y), b.z);
             });
+            fell(&bricks, i + 1);
         }
         bricks.sort_by_key(|b| b.z0);
         let mut voxels: HashMap<(i32, i32, i32), usize> = HashMap::new();
@@ -104,23 +118,21 @@ impl Stack {
             let up = bricks[i]
                 .top()
                 .iter()
-                .flat_map(|b| voxels.get(&(b.x, b.y, b.z + 1)))
-                .map(|j| j.clone())
+                .flat_map(|b| voxels.get(&(b.x, b.y, b.z + 1))).copied()
                 .collect::<HashSet<_>>();
             above.insert(i, up);
             let down = bricks[i]
                 .bottom()
                 .iter()
-                .flat_map(|b| voxels.get(&(b.x, b.y, b.z - 1)))
-                .map(|j| j.clone())
+                .flat_map(|b| voxels.get(&(b.x, b.y, b.z - 1))).copied()
                 .collect::<HashSet<_>>();
             below.insert(i, down);
         }
-        Stack {
+        Ok(Stack {
             count: bricks.len(),
             above,
             below,
-        }
+        })
     }
 
     fn num_can_disintegrate(&self) -> usize {
@@ -173,28 +185,48 @@ impl Stack {
     }
 }
 
-fn main() {
-    let stack = Stack::create(include_str!("../data/input.txt"));
-    println!("Part 1: {}", stack.num_can_disintegrate());
-    println!("Part 2: {}", stack.max_fall_after_single_disintegration());
+impl Solution for Day {
+    /// The bricks, once they have settled.
+    type Input<'a> = Stack;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Stack, ParseError> {
+        Stack::create(input, |_, _| {})
+    }
+
+    /// Number of bricks that could be safely disintegrated.
+    fn part1(stack: &Stack) -> usize {
+        stack.num_can_disintegrate()
+    }
+
+    /// Sum of the number of other bricks that would fall for each brick disintegrated.
+    fn part2(stack: &Stack) -> usize {
+        stack.max_fall_after_single_disintegration()
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::Stack;
-
-    const TEST_INPUT: &str = include_str!("../data/example.txt");
+    use crate::Day;
+    use aoc_core::{example, Solution};
 
     #[test]
     fn test_part1() {
-        assert_eq!(5, Stack::create(TEST_INPUT).num_can_disintegrate());
+        assert_eq!(5, Day::part1(&Day::parse(&example!()).unwrap()));
     }
 
     #[test]
     fn test_part2() {
+        assert_eq!(7, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_diagonal_brick() {
+        let input = example!().replace("1,0,1~1,2,1", "1,0,1~2,2,1");
         assert_eq!(
-            7,
-            Stack::create(TEST_INPUT).max_fall_after_single_disintegration()
+            "line 1, column 1: expected a straight brick, found '1'",
+            Day::parse(&input).err().unwrap().to_string()
         );
     }
 }
@@ -0,0 +1,98 @@
+//! Pictures of the bricks falling into place.
+
+use crate::{Brick, Stack};
+use aoc_core::parse::ParseError;
+use aoc_core::render::{Frame, Rgb};
+use std::cmp::Reverse;
+
+/// Empty space, the ground, bricks still falling, then colours for the settled bricks.
+const PALETTE: &[Rgb] = &[
+    [8, 8, 16],
+    [90, 70, 50],
+    [110, 110, 120],
+    [220, 80, 70],
+    [240, 170, 60],
+    [230, 220, 90],
+    [90, 190, 100],
+    [80, 150, 230],
+    [170, 110, 220],
+];
+
+/// How far the bricks reach along each axis, from 0.
+#[derive(Clone, Copy)]
+struct Size {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+impl Size {
+    fn of(bricks: &[Brick]) -> Size {
+        let blocks = || bricks.iter().flat_map(|b| &b.blocks);
+        Size {
+            x: blocks().map(|b| b.x as usize).max().unwrap_or(0),
+            y: blocks().map(|b| b.y as usize).max().unwrap_or(0),
+            z: blocks().map(|b| b.z as usize).max().unwrap_or(0),
+        }
+    }
+}
+
+/// Draws the bricks from the front (with x across) and from the side (with y across), next to
+/// each other, the ground at the bottom. Nearer bricks hide those behind them, and the ones that
+/// have settled are coloured in.
+fn draw(bricks: &[Brick], settled: usize, size: Size) -> Frame {
+    let mut frame = Frame::new(size.x + size.y + 3, size.z + 1, PALETTE);
+    for x in (0..frame.width()).filter(|&x| x != size.x + 1) {
+        frame.set(x, size.z, 1);
+    }
+    let colour = |i: usize| if i < settled { 3 + (i % 6) as u8 } else { 2 };
+
+    let mut order: Vec<usize> = (0..bricks.len()).collect();
+    order.sort_by_key(|&i| Reverse(bricks[i].blocks.iter().map(|b| b.y).min()));
+    for &i in &order {
+        for b in &bricks[i].blocks {
+            frame.set(b.x as usize, size.z - b.z as usize, colour(i));
+        }
+    }
+    order.sort_by_key(|&i| Reverse(bricks[i].blocks.iter().map(|b| b.x).min()));
+    for &i in &order {
+        for b in &bricks[i].blocks {
+            frame.set(size.x + 2 + b.y as usize, size.z - b.z as usize, colour(i));
+        }
+    }
+    frame
+}
+
+/// Draws the bricks where they are in the snapshot, then again as each one, lowest first, falls
+/// as far as it can.
+pub fn visualise(input: &str, frame: &mut dyn FnMut(&Frame)) -> Result<(), ParseError> {
+    let mut size = None;
+    Stack::create(input, |bricks, settled| {
+        // The bricks only fall, so the first picture is the tallest.
+        let size = *size.get_or_insert_with(|| Size::of(bricks));
+        frame(&draw(bricks, settled, size));
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::visualise;
+    use aoc_core::example;
+
+    #[test]
+    fn test_visualise() {
+        let mut frames = vec![];
+        visualise(&example!(), &mut |f| frames.push(f.clone())).unwrap();
+        assert_eq!(8, frames.len());
+        let last = frames.last().unwrap();
+        assert_eq!((7, 10), (last.width(), last.height()));
+        // Brick A lies along y at the bottom, so is seen end-on from the front and whole from
+        // the side. The last brick, G, falls from the top to stand on F, and as the seventh it
+        // takes the first brick's colour again.
+        assert_eq!(3, last.get(1, 8));
+        assert_eq!([3, 3, 3], [4, 5, 6].map(|x| last.get(x, 8)));
+        assert_eq!([2, 2], [0, 1].map(|y| frames[0].get(1, y)));
+        assert_eq!([3, 3], [3, 4].map(|y| last.get(1, y)));
+    }
+}
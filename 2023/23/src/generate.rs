@@ -0,0 +1,154 @@
+//! Random hiking trail maps of any size, for stress testing.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::{HashMap, VecDeque};
+
+const DIRS: [(isize, isize, char); 4] = [(-1, 0, '^'), (1, 0, 'v'), (0, -1, '<'), (0, 1, '>')];
+
+/// A square map `size` tiles wide (rounded up to an odd number), laid out like the real inputs:
+/// one-tile-wide paths with no dead ends, from a gap in the top row to one in the bottom row,
+/// meeting at junctions whose entrances are slopes. The slopes always lead closer to the end, so
+/// the hike can't go round in circles or get stuck until they can be climbed.
+///
+/// There are about `size * size / 1000` loops, and around twice as many junctions, so the real
+/// inputs' size of 141 gives a similar number of junctions to them.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(5) | 1;
+    let mut open = vec![vec![false; size]; size];
+
+    // Carve a random spanning tree through the tiles at odd coordinates, with a path between each
+    // and its parent.
+    let cells = (size - 1) / 2;
+    let mut stack = vec![(1, 1)];
+    open[1][1] = true;
+    while let Some(&(r, c)) = stack.last() {
+        let unvisited: Vec<_> = DIRS
+            .iter()
+            .map(|&(dr, dc, _)| (r as isize + 2 * dr, c as isize + 2 * dc))
+            .filter(|&(r, c)| r > 0 && c > 0 && r < 2 * cells as isize && c < 2 * cells as isize)
+            .map(|(r, c)| (r as usize, c as usize))
+            .filter(|&(r, c)| !open[r][c])
+            .collect();
+        match unvisited.choose(&mut rng) {
+            Some(&(r1, c1)) => {
+                open[(r + r1) / 2][(c + c1) / 2] = true;
+                open[r1][c1] = true;
+                stack.push((r1, c1));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // Knock through some of the remaining walls between them to make loops.
+    let mut walls: Vec<(usize, usize)> = (1..size - 1)
+        .flat_map(|r| (1..size - 1).map(move |c| (r, c)))
+        .filter(|&(r, c)| (r + c) % 2 == 1 && !open[r][c])
+        .collect();
+    walls.shuffle(&mut rng);
+    for &(r, c) in walls.iter().take(size * size / 1000) {
+        open[r][c] = true;
+    }
+
+    let (start, end) = ((0, 1), (size - 1, size - 2));
+    open[start.0][start.1] = true;
+    open[end.0][end.1] = true;
+    let neighbours = |open: &Vec<Vec<bool>>, (r, c): (usize, usize)| {
+        DIRS.iter()
+            .map(|&(dr, dc, _)| (r.wrapping_add_signed(dr), c.wrapping_add_signed(dc)))
+            .filter(|&(r, c)| r < size && c < size && open[r][c])
+            .collect::<Vec<_>>()
+    };
+
+    // Fill in dead ends until there are none left.
+    let mut queue: VecDeque<_> = (0..size).flat_map(|r| (0..size).map(move |c| (r, c))).collect();
+    while let Some(p) = queue.pop_front() {
+        if open[p.0][p.1] && p != start && p != end && neighbours(&open, p).len() < 2 {
+            open[p.0][p.1] = false;
+            queue.extend(neighbours(&open, p));
+        }
+    }
+
+    // Find the junctions, and follow the paths out of them to the next junction, start or end.
+    let mut nodes = vec![start, end];
+    nodes.extend(
+        (0..size)
+            .flat_map(|r| (0..size).map(move |c| (r, c)))
+            .filter(|&p| open[p.0][p.1] && neighbours(&open, p).len() > 2),
+    );
+    let index: HashMap<_, _> = nodes.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+    let mut paths = vec![];
+    for (i, &node) in nodes.iter().enumerate().skip(2) {
+        for first in neighbours(&open, node) {
+            let (mut prev, mut cur) = (node, first);
+            while !index.contains_key(&cur) {
+                let next = neighbours(&open, cur).into_iter().find(|&n| n != prev).unwrap();
+                (prev, cur) = (cur, next);
+            }
+            paths.push((i, first, index[&cur]));
+        }
+    }
+
+    // Order the junctions by how many junctions away from the end they are, and point the slopes
+    // at the ends of each path from the farther junction to the nearer one.
+    let mut dist = vec![usize::MAX; nodes.len()];
+    dist[1] = 0;
+    let mut queue = VecDeque::from([1]);
+    while let Some(i) = queue.pop_front() {
+        let adjacent: Vec<usize> = paths
+            .iter()
+            .filter(|p| p.2 == i)
+            .map(|p| p.0)
+            .chain(paths.iter().filter(|p| p.0 == i).map(|p| p.2))
+            .collect();
+        for j in adjacent {
+            if dist[j] == usize::MAX {
+                dist[j] = dist[i] + 1;
+                queue.push_back(j);
+            }
+        }
+    }
+
+    let mut tiles: Vec<Vec<char>> = open
+        .iter()
+        .map(|row| row.iter().map(|&o| if o { '.' } else { '#' }).collect())
+        .collect();
+    for &(i, (r, c), j) in &paths {
+        if index.contains_key(&(r, c)) {
+            continue;
+        }
+        let (jr, jc) = nodes[i];
+        let (dr, dc) = (r as isize - jr as isize, c as isize - jc as isize);
+        let outwards = (dist[i], i) > (dist[j], j);
+        let (dr, dc) = if outwards { (dr, dc) } else { (-dr, -dc) };
+        tiles[r][c] = DIRS.iter().find(|d| (d.0, d.1) == (dr, dc)).unwrap().2;
+    }
+
+    tiles
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::Day;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 21);
+        assert_eq!(input, generate(1, 21));
+        // Without any loops, there's only one way through.
+        let map = Day::parse(&input).unwrap();
+        assert_eq!(Day::part1(&map), Day::part2(&map));
+
+        let map = Day::parse(&generate(1, 81)).unwrap();
+        assert!(Day::part1(&map) < Day::part2(&map));
+    }
+}
@@ -0,0 +1,498 @@
+use aoc_core::geom::Dir4;
+use aoc_core::grid::{Grid, Pos};
+use aoc_core::lint::Shape;
+use aoc_core::parse::{self, Line, ParseError};
+use aoc_core::search;
+use aoc_core::stats::{self, Histogram};
+use aoc_core::{threads, Solution};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+mod generate;
+
+pub use generate::generate;
+
+pub struct Day;
+
+pub struct Map {
+    tiles: Grid<char>,
+}
+
+/// A hike from the start to the end.
+pub struct Hike {
+    /// The number of steps taken.
+    pub len: usize,
+    /// Every tile stepped on, from the start to the end.
+    pub route: Vec<Pos>,
+}
+
+/// A corridor from one junction to another.
+struct Corridor {
+    to: usize,
+    /// The tiles along the corridor, leaving out the junction it starts from.
+    tiles: Vec<Pos>,
+}
+
+/// The junction where every hike starts, and the one where it ends.
+const START: usize = 0;
+const END: usize = 1;
+
+/// The map contracted to the places a hike can choose which way to go, and the corridors
+/// between them.
+struct Graph {
+    /// Where each junction is: the start, the end, then the rest in reading order.
+    junctions: Vec<Pos>,
+    /// The corridors leading out of each junction, longest first.
+    corridors: Vec<Vec<Corridor>>,
+}
+
+/// A hike through the junctions, as the corridor taken out of each, numbered by the junction
+/// it leaves from and its place among that junction's corridors.
+type Steps = Vec<(usize, usize)>;
+
+impl Graph {
+    /// The junction a hike taking `steps` from the start has got to.
+    fn at(&self, steps: &Steps) -> usize {
+        steps.last().map_or(START, |&(from, k)| self.corridors[from][k].to)
+    }
+}
+
+impl Map {
+    /// Parses the map, checking that there's a way down the slopes from a gap in the top row to
+    /// one in the bottom row, so that both parts have a hike to find.
+    fn parse(s: &str) -> Result<Map, ParseError> {
+        let tiles = Grid::parse(s, "#.^>v<", |c| c)?;
+        let rows: Vec<Line> = parse::lines(s).take(tiles.height()).collect();
+        if rows.len() < 2 {
+            return Err(ParseError::end_of_input(rows.len(), "a second row"));
+        }
+        for (row, edge) in [(rows[0], "top"), (rows[rows.len() - 1], "bottom")] {
+            if !row.text().contains('.') {
+                return Err(row.error(format!("a path through the {} row", edge)));
+            }
+        }
+
+        let map = Map { tiles };
+        let (start, end) = (map.start_pos(), map.end_pos());
+        let explored = search::bfs([start], |&p| map.neighbours(p, true), |&p| p == end, |_| {});
+        if explored.goal().is_none() {
+            let expected = "a way here from the start, down the slopes";
+            return Err(rows[end.0].error_at(end.1, expected));
+        }
+        Ok(map)
+    }
+
+    fn start_pos(&self) -> Pos {
+        let c = self.tiles.row(0).iter().position(|&t| t == '.');
+        (0, c.expect("No start point"))
+    }
+
+    fn end_pos(&self) -> Pos {
+        let r = self.tiles.height() - 1;
+        let c = self.tiles.row(r).iter().position(|&t| t == '.');
+        (r, c.expect("No end point"))
+    }
+
+    /// The tiles next to `p` that a hike can step on to, going only down slopes if `dir`.
+    fn neighbours(&self, p: Pos, dir: bool) -> Vec<Pos> {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(|d| {
+                let next = self.tiles.step(p, d)?;
+                let open = match self.tiles[next] {
+                    '#' => false,
+                    '.' => true,
+                    slope => !dir || Dir4::from_glyph(slope) == Some(d),
+                };
+                open.then_some(next)
+            })
+            .collect()
+    }
+
+    /// Follows the corridors out of every junction, going only down slopes if `dir`. Corridors
+    /// that come to a dead end are left out, and of two between the same junctions only the
+    /// longer is kept. Reports the junctions and the lengths of the corridors.
+    fn graph(&self, dir: bool) -> Graph {
+        let mut junctions = vec![self.start_pos(), self.end_pos()];
+        for (p, &tile) in self.tiles.iter() {
+            if tile != '#' && self.neighbours(p, false).len() > 2 {
+                junctions.push(p);
+            }
+        }
+        let index: HashMap<Pos, usize> =
+            junctions.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+
+        let mut corridors = Vec::with_capacity(junctions.len());
+        for &p in &junctions {
+            let mut out: Vec<Corridor> = vec![];
+            for first in self.neighbours(p, dir) {
+                let (mut prev, mut cur) = (p, first);
+                let mut tiles = vec![first];
+                let to = loop {
+                    if let Some(&to) = index.get(&cur) {
+                        break Some(to);
+                    }
+                    let Some(next) = self.neighbours(cur, dir).into_iter().find(|&n| n != prev)
+                    else {
+                        break None;
+                    };
+                    (prev, cur) = (cur, next);
+                    tiles.push(next);
+                };
+                if let Some(to) = to {
+                    out.push(Corridor { to, tiles });
+                }
+            }
+            out.sort_by_key(|c| (c.to, Reverse(c.tiles.len())));
+            out.dedup_by_key(|c| c.to);
+            out.sort_by_key(|c| (Reverse(c.tiles.len()), c.to));
+            corridors.push(out);
+        }
+
+        stats::count("junctions", junctions.len() as u64);
+        if stats::enabled() {
+            let mut lengths = Histogram::new();
+            corridors.iter().flatten().for_each(|c| lengths.add(c.tiles.len() as u64));
+            stats::histogram("corridor length", &lengths);
+        }
+        Graph {
+            junctions,
+            corridors,
+        }
+    }
+
+    /// The longest hike from the start to the end, following the slopes if `dir`, or `None` if
+    /// the end can't be reached. The search is split between `threads` threads, and finds the
+    /// same hike however many there are. Reports the junctions, the lengths of the corridors
+    /// between them, and how many partial hikes were expanded, cut short or completed.
+    pub fn longest_hike(&self, dir: bool, threads: usize) -> Option<Hike> {
+        let graph = self.graph(dir);
+        let bounds = Bounds::new(&graph);
+
+        // Give each thread several hikes to start from, so they finish at about the same time.
+        let mut prefixes = vec![vec![]];
+        while threads > 1 && prefixes.len() < threads * 4 {
+            if prefixes.iter().all(|p| graph.at(p) == END) {
+                break;
+            }
+            prefixes = prefixes.into_iter().flat_map(|p| bounds.extend(&graph, p)).collect();
+        }
+
+        let best = AtomicUsize::new(0);
+        let results: Vec<Mutex<Option<Search>>> =
+            prefixes.iter().map(|_| Mutex::new(None)).collect();
+        let next = AtomicUsize::new(0);
+        thread::scope(|s| {
+            for _ in 0..threads.max(1) {
+                s.spawn(|| loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(prefix) = prefixes.get(i) else { break };
+                    let mut search = Search::new(&graph, &bounds, prefix, &best);
+                    search.dfs(graph.at(prefix));
+                    *results[i].lock().unwrap() = Some(search);
+                });
+            }
+        });
+
+        // The earliest of the longest hikes, so it doesn't depend on which thread got there first.
+        let (mut found, mut expanded, mut pruned, mut complete) = (None, 0, 0, 0);
+        for result in results {
+            let search = result.into_inner().unwrap().unwrap();
+            expanded += search.expanded;
+            pruned += search.pruned;
+            complete += search.complete;
+            if let Some((len, route)) = search.found {
+                if found.as_ref().is_none_or(|(best, _)| len > *best) {
+                    found = Some((len, route));
+                }
+            }
+        }
+        stats::count("paths expanded", expanded);
+        stats::count("paths pruned", pruned);
+        stats::count("complete paths", complete);
+
+        let (len, steps) = found?;
+        let mut route = vec![graph.junctions[START]];
+        for &(from, k) in &steps {
+            route.extend(&graph.corridors[from][k].tiles);
+        }
+        Some(Hike { len, route })
+    }
+}
+
+/// Which corridors a hike can usefully take.
+struct Bounds {
+    /// The only junction with a corridor to the end, if there's just one. A hike that leaves it
+    /// any other way can never finish.
+    exit: Option<usize>,
+}
+
+impl Bounds {
+    fn new(graph: &Graph) -> Bounds {
+        let into_end: Vec<usize> = (0..graph.junctions.len())
+            .filter(|&from| graph.corridors[from].iter().any(|c| c.to == END))
+            .collect();
+        let exit = match into_end[..] {
+            [from] => Some(from),
+            _ => None,
+        };
+        Bounds { exit }
+    }
+
+    /// Whether it's worth taking a corridor from junction `at` to junction `to`.
+    fn allows(&self, at: usize, to: usize) -> bool {
+        self.exit != Some(at) || to == END
+    }
+
+    /// Each way a hike taking `steps` can go on by one more corridor, or just `steps` if it's
+    /// already at the end.
+    fn extend(&self, graph: &Graph, steps: Steps) -> Vec<Steps> {
+        let at = graph.at(&steps);
+        if at == END {
+            return vec![steps];
+        }
+        let visited = Visited::along(graph, &steps);
+        (graph.corridors[at].iter().enumerate())
+            .filter(|(_, c)| !visited.contains(c.to) && self.allows(at, c.to))
+            .map(|(k, _)| {
+                let mut next = steps.clone();
+                next.push((at, k));
+                next
+            })
+            .collect()
+    }
+}
+
+/// The junctions a hike has been through, as a bitset with room for any number of them.
+struct Visited(Vec<u64>);
+
+impl Visited {
+    fn new(junctions: usize) -> Visited {
+        Visited(vec![0; junctions.div_ceil(64)])
+    }
+
+    /// The junctions visited by a hike taking `steps` from the start.
+    fn along(graph: &Graph, steps: &Steps) -> Visited {
+        let mut visited = Visited::new(graph.junctions.len());
+        visited.insert(START);
+        for &(from, k) in steps {
+            visited.insert(graph.corridors[from][k].to);
+        }
+        visited
+    }
+
+    fn insert(&mut self, j: usize) {
+        self.0[j / 64] |= 1 << (j % 64);
+    }
+
+    fn remove(&mut self, j: usize) {
+        self.0[j / 64] &= !(1 << (j % 64));
+    }
+
+    fn contains(&self, j: usize) -> bool {
+        self.0[j / 64] & (1 << (j % 64)) != 0
+    }
+}
+
+/// A depth-first search for the longest hike that carries on from some first few steps.
+struct Search<'a> {
+    graph: &'a Graph,
+    bounds: &'a Bounds,
+    /// The longest hike any search has finished yet.
+    best: &'a AtomicUsize,
+    steps: Steps,
+    visited: Visited,
+    len: usize,
+    /// Scratch space for working out how much further a hike could go: the junctions it can
+    /// still reach, and the longest corridor into each of them.
+    reached: Visited,
+    reachable: Vec<usize>,
+    longest_in: Vec<usize>,
+    /// The first of the longest hikes this search has finished, and its length.
+    found: Option<(usize, Steps)>,
+    expanded: u64,
+    pruned: u64,
+    complete: u64,
+}
+
+impl<'a> Search<'a> {
+    fn new(graph: &'a Graph, bounds: &'a Bounds, steps: &Steps, best: &'a AtomicUsize) -> Self {
+        let visited = Visited::along(graph, steps);
+        let len = steps.iter().map(|&(from, k)| graph.corridors[from][k].tiles.len()).sum();
+        let junctions = graph.junctions.len();
+        Search {
+            graph,
+            bounds,
+            best,
+            steps: steps.clone(),
+            visited,
+            len,
+            reached: Visited::new(junctions),
+            reachable: Vec::with_capacity(junctions),
+            longest_in: vec![0; junctions],
+            found: None,
+            expanded: 0,
+            pruned: 0,
+            complete: 0,
+        }
+    }
+
+    /// At most how much further a hike at junction `at` could go, or `None` if it can't get
+    /// to the end at all. It can only go through the junctions it can still reach, and into
+    /// each of those at most once.
+    fn further(&mut self, at: usize) -> Option<usize> {
+        let (graph, bounds) = (self.graph, self.bounds);
+        self.reached.0.fill(0);
+        self.reachable.clear();
+        self.reachable.push(at);
+        let mut i = 0;
+        while let Some(&from) = self.reachable.get(i) {
+            i += 1;
+            // A hike stops at the end.
+            if from == END {
+                continue;
+            }
+            for c in &graph.corridors[from] {
+                if self.visited.contains(c.to) || !bounds.allows(from, c.to) {
+                    continue;
+                }
+                if !self.reached.contains(c.to) {
+                    self.reached.insert(c.to);
+                    self.reachable.push(c.to);
+                }
+                self.longest_in[c.to] = self.longest_in[c.to].max(c.tiles.len());
+            }
+        }
+
+        let further = self.reachable[1..].iter().map(|&j| self.longest_in[j]).sum();
+        for &j in &self.reachable {
+            self.longest_in[j] = 0;
+        }
+        self.reached.contains(END).then_some(further)
+    }
+
+    /// Tries every way on from junction `at`, giving up on any hike that couldn't get as long
+    /// as the best one already found.
+    fn dfs(&mut self, at: usize) {
+        if at == END {
+            self.complete += 1;
+            if self.found.as_ref().is_none_or(|(len, _)| self.len > *len) {
+                self.found = Some((self.len, self.steps.clone()));
+                self.best.fetch_max(self.len, Ordering::Relaxed);
+            }
+            return;
+        }
+        // Hikes as long as the best are still followed, so every search finds its first.
+        match self.further(at) {
+            Some(further) if self.len + further >= self.best.load(Ordering::Relaxed) => {}
+            _ => {
+                self.pruned += 1;
+                return;
+            }
+        }
+        self.expanded += 1;
+
+        let (graph, bounds) = (self.graph, self.bounds);
+        for (k, c) in graph.corridors[at].iter().enumerate() {
+            if self.visited.contains(c.to) || !bounds.allows(at, c.to) {
+                continue;
+            }
+            self.visited.insert(c.to);
+            self.len += c.tiles.len();
+            self.steps.push((at, k));
+            self.dfs(c.to);
+            self.steps.pop();
+            self.len -= c.tiles.len();
+            self.visited.remove(c.to);
+        }
+    }
+}
+
+impl Solution for Day {
+    type Input<'a> = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::parse(input)
+    }
+
+    /// Length of the longest hike that respects the slopes.
+    fn part1(map: &Map) -> usize {
+        map.longest_hike(true, threads()).expect("No hike found").len
+    }
+
+    /// Length of the longest hike when slopes can be climbed.
+    fn part2(map: &Map) -> usize {
+        map.longest_hike(false, threads()).expect("No hike found").len
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{generate, Day, Map};
+    use aoc_core::{example, Solution};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(94, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day::parse(input).err().unwrap().to_string();
+        assert_eq!("line 2, column 1: expected a second row, found end of input", error("#.#\n"));
+        assert_eq!(
+            "line 1, column 1: expected a path through the top row, found '#'",
+            error("###\n#.#\n#.#\n")
+        );
+        assert_eq!(
+            "line 3, column 1: expected a path through the bottom row, found '#'",
+            error("#.#\n#.#\n###\n")
+        );
+        assert_eq!(
+            "line 3, column 2: expected a way here from the start, down the slopes, found '.'",
+            error("#.#\n#^#\n#.#\n")
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(154, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+
+    /// Checks the longest hike is a walk from the start to the end that never steps on a tile
+    /// twice, and is the same however many threads search for it.
+    fn check_hike(map: &Map, dir: bool) -> usize {
+        let hike = map.longest_hike(dir, 1).unwrap();
+        assert_eq!(hike.len + 1, hike.route.len());
+        assert_eq!(Some(&map.start_pos()), hike.route.first());
+        assert_eq!(Some(&map.end_pos()), hike.route.last());
+        assert!(hike.route.windows(2).all(|w| map.neighbours(w[0], dir).contains(&w[1])));
+        assert_eq!(hike.route.len(), hike.route.iter().collect::<HashSet<_>>().len());
+
+        let parallel = map.longest_hike(dir, 4).unwrap();
+        assert_eq!(hike.route, parallel.route);
+        hike.len
+    }
+
+    #[test]
+    fn test_route() {
+        let map = Day::parse(&example!()).unwrap();
+        assert_eq!(94, check_hike(&map, true));
+        assert_eq!(154, check_hike(&map, false));
+    }
+
+    #[test]
+    fn test_many_junctions() {
+        let map = Day::parse(&generate(3, 201)).unwrap();
+        assert!(map.graph(false).junctions.len() > 64);
+        assert_eq!(3398, check_hike(&map, true));
+        assert_eq!(3546, check_hike(&map, false));
+    }
+}
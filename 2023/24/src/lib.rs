@@ -1,3 +1,8 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
 // All the numbers here are huge, so we use a multiplier to ensure we don't lose too much accuracy
 // when dealing with smallish numbers. I guess this is why the bounds on the input data are in this
 // order-of-magnitude..?
@@ -6,7 +11,7 @@ const PRECISION_FUDGE: f64 = 100000000000000.;
 // This is the range of integer velocity values to search for on each axis.
 const SEARCH_RANGE: std::ops::Range<i64> = -300..300;
 
-struct Line {
+pub struct Line {
     x1: f64,
     y1: f64,
     z1: f64,
@@ -16,22 +21,31 @@ struct Line {
 }
 
 impl Line {
-    fn parse(s: &str) -> Line {
-        let [x1, y1, z1, dx, dy, dz] = s
-            .split(&[',', '@'][..])
-            .map(|s| s.trim().parse::<f64>().unwrap())
-            .collect::<Vec<_>>()[..]
-        else {
-            unreachable!("Invalid input")
+    /// Parses a hailstone, e.g. `19, 13, 30 @ -2,  1, -2`.
+    fn parse(line: &mut parse::Line) -> Result<Line, ParseError> {
+        let triple = |line: &mut parse::Line| -> Result<[f64; 3], ParseError> {
+            let x = line.number::<i64>()?;
+            line.tag(",")?;
+            line.spaces();
+            let y = line.number::<i64>()?;
+            line.tag(",")?;
+            line.spaces();
+            let z = line.number::<i64>()?;
+            Ok([x as f64, y as f64, z as f64])
         };
-        Line {
+        let [x1, y1, z1] = triple(line)?;
+        line.spaces();
+        line.tag("@")?;
+        line.spaces();
+        let [dx, dy, dz] = triple(line)?;
+        Ok(Line {
             x1,
             y1,
             z1,
             dx,
             dy,
             dz,
-        }
+        })
     }
 
     fn intersection_xy(a: &Line, b: &Line) -> Option<(f64, f64)> {
@@ -90,7 +104,7 @@ impl Line {
             return true;
         }
         let u = ((u1 * v2 - v1 * u2) * (u3 - u4) - (u1 - u2) * (u3 * v4 - v3 * u4)) / det;
-        return (u - u1) / adu >= 0. && (u - u3) / bdu >= 0.;
+        (u - u1) / adu >= 0. && (u - u3) / bdu >= 0.
     }
 
     fn offset(&self, ox: i64, oy: i64, oz: i64) -> Line {
@@ -113,12 +127,7 @@ impl Line {
     }
 }
 
-fn part1(input: &str, bounds: (f64, f64)) -> usize {
-    let lines: Vec<Line> = input
-        .trim()
-        .split("\n")
-        .map(|s| Line::parse(s /*, bounds*/))
-        .collect();
+fn count_intersections(lines: &[Line], bounds: (f64, f64)) -> usize {
     let mut total = 0;
     for i in 0..lines.len() {
         for j in i..lines.len() {
@@ -132,9 +141,28 @@ fn part1(input: &str, bounds: (f64, f64)) -> usize {
     total
 }
 
-fn part2(input: &str) -> i64 {
-    let lines: Vec<Line> = input.trim().split("\n").map(|s| Line::parse(s)).collect();
+impl Solution for Day {
+    /// The position and velocity of each hailstone.
+    type Input<'a> = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = i64;
 
+    fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+        parse::each_line(input, Line::parse)
+    }
+
+    /// Number of hailstone paths that cross inside the test area.
+    fn part1(lines: &Vec<Line>) -> usize {
+        count_intersections(lines, (200000000000000., 400000000000000.))
+    }
+
+    /// Sum of the coordinates of the rock's initial position.
+    fn part2(lines: &Vec<Line>) -> i64 {
+        find_rock(lines)
+    }
+}
+
+fn find_rock(lines: &[Line]) -> i64 {
     // Find a vx, vy offset pair where _all_ lines intersect in the XY plane.
     for vx in SEARCH_RANGE {
         'a: for vy in SEARCH_RANGE {
@@ -192,25 +220,16 @@ fn part2(input: &str) -> i64 {
     unreachable!("No solution found");
 }
 
-fn main() {
-    let input = include_str!("../data/input.txt");
-    println!(
-        "Part 1: {}",
-        part1(input, (200000000000000., 400000000000000.))
-    );
-    println!("Part 2: {}", part2(input));
-}
-
 #[cfg(test)]
 mod test {
-    use crate::{part1, Line};
+    use crate::{count_intersections, Day, Line};
+    use aoc_core::{example, parse, Solution};
 
-    const TEST_INPUT: &str = include_str!("../data/example.txt");
     const TEST_BOUNDS: (f64, f64) = (7., 27.);
 
     #[test]
     fn test_part1() {
-        assert_eq!(2, part1(TEST_INPUT, TEST_BOUNDS));
+        assert_eq!(2, count_intersections(&Day::parse(&example!()).unwrap(), TEST_BOUNDS));
     }
 
     #[test]
@@ -218,8 +237,8 @@ mod test {
         assert_eq!(
             Some((14.333333333333334, 15.333333333333334)),
             Line::intersection_xy(
-                &Line::parse("19, 13, 30 @ -2, 1, -2"),
-                &Line::parse("18, 19, 22 @ -1, -1, -2"),
+                &Line::parse(&mut parse::Line::new(1, "19, 13, 30 @ -2, 1, -2")).unwrap(),
+                &Line::parse(&mut parse::Line::new(2, "18, 19, 22 @ -1, -1, -2")).unwrap(),
             )
         );
     }
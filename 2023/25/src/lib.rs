@@ -0,0 +1,107 @@
+use aoc_core::parse::{self, ParseError};
+use aoc_core::stats::{self, Histogram};
+use aoc_core::Solution;
+use rand::{thread_rng, Rng};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day;
+
+/// Product of the sizes of the two groups either side of the only cut of three wires. Reports
+/// how many random contractions it took to find it, and the sizes of the cuts they found.
+fn solve(orig_edges: &[(&str, &str)]) -> usize {
+    let orig_nodes = orig_edges
+        .iter()
+        .flat_map(|&(from, to)| [from, to])
+        .collect::<HashSet<_>>();
+
+    let mut rng = thread_rng();
+    let mut cuts = Histogram::new();
+
+    // Rough implementation of
+    // https://en.wikipedia.org/wiki/Karger%27s_algorithm
+    loop {
+        let mut edges = orig_edges.to_vec();
+        let mut nodes = orig_nodes.clone();
+        let mut counts = nodes.iter().map(|n| (*n, 1)).collect::<HashMap<_, _>>();
+        while nodes.len() > 2 {
+            let idx = rng.gen_range(0..edges.len());
+            let (from, to) = edges.remove(idx);
+            for i in 0..edges.len() {
+                if edges[i].0 == to {
+                    edges[i].0 = from;
+                }
+                if edges[i].1 == to {
+                    edges[i].1 = from;
+                }
+            }
+            edges.retain_mut(|(a, b)| a != b);
+            nodes.remove(&to);
+            counts.insert(from, counts.get(from).unwrap() + counts.get(to).unwrap());
+        }
+        cuts.add(edges.len() as u64);
+        if edges.len() == 3 {
+            stats::count("karger trials", cuts.count());
+            stats::histogram("cut size", &cuts);
+            return nodes.iter().map(|n| counts.get(n).unwrap()).product();
+        }
+    }
+}
+
+impl Solution for Day {
+    /// Every wire between two components.
+    type Input<'a> = Vec<(&'a str, &'a str)>;
+    type Part1 = usize;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<(&str, &str)>, ParseError> {
+        let mut edges = vec![];
+        for (from, to_list) in parse::each_line(input, |l| {
+            let from = l.word()?;
+            l.tag(": ")?;
+            Ok((from, l.list(" ", |l| l.word())?))
+        })? {
+            for to in to_list {
+                edges.push((from, to));
+            }
+        }
+        Ok(edges)
+    }
+
+    /// Product of the sizes of the two groups left after cutting three wires.
+    fn part1(edges: &Vec<(&str, &str)>) -> usize {
+        solve(edges)
+    }
+
+    /// Day 25 has no second puzzle; the final star is awarded for completing every other day.
+    fn part2(_edges: &Vec<(&str, &str)>) -> &'static str {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::stats::{self, Stat};
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(54, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_stats() {
+        let input = example!();
+        let edges = Day::parse(&input).unwrap();
+        let (_, stats) = stats::collect(|| Day::part1(&edges));
+        let Some(&Stat::Count(trials)) = stats.get("karger trials") else {
+            panic!("No count of trials");
+        };
+        let Some(Stat::Histogram(cuts)) = stats.get("cut size") else {
+            panic!("No histogram of cut sizes");
+        };
+        // Every trial finds a cut, and only the last is the smallest.
+        assert_eq!(trials, cuts.count());
+        assert_eq!(Some(3), cuts.min());
+    }
+}
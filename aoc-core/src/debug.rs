@@ -0,0 +1,52 @@
+//! Stopping points in a simulation, for the runner's step debugger.
+//!
+//! A day that can be debugged runs its machine (a CPU, a circuit, a troop of monkeys) with a hook
+//! that's handed a [`State`] after every step: a cycle, a pulse or an inspection. The debugger
+//! decides whether to stop there, tests breakpoints against the state's [`fields`](State::fields)
+//! and shows it when asked, so the days never need to know about commands or breakpoints.
+
+use std::fmt;
+
+/// A value that breakpoints can test.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Self {
+        Value::Int(n)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Text(s.to_string())
+    }
+}
+
+/// A simulation just after one of its steps.
+pub trait State {
+    /// The field that counts the larger steps the small ones make up, such as a button press
+    /// made up of pulses, which the debugger's `next` runs to the end of.
+    fn unit(&self) -> &'static str;
+
+    /// The step just taken, in a line.
+    fn describe(&self) -> String;
+
+    /// The values breakpoints can test, by name.
+    fn fields(&self) -> Vec<(&'static str, Value)>;
+
+    /// Everything there is to see, over as many lines as it takes.
+    fn inspect(&self) -> String;
+}
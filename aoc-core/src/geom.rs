@@ -0,0 +1,350 @@
+//! Points and directions, for the puzzles that move things around a plane or through space.
+//!
+//! `y` grows downwards, as rows do in a [`Grid`](crate::grid::Grid), so north is towards smaller
+//! `y` and a point's `(y, x)` is a grid position once it's known to be inside.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    /// The distance to `other` moving only along the axes.
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The distance to `other` moving diagonals too, like a king.
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The point with each coordinate replaced by its sign: the step of at most one in each
+    /// axis towards it from the origin.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    /// The distance to `other` moving only along the axes.
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The point with each coordinate replaced by its sign.
+    pub fn signum(self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+}
+
+/// Arithmetic on each coordinate of a point.
+macro_rules! point_ops {
+    ($point:ident { $($axis:ident),* }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($axis: self.$axis + other.$axis),* }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($axis: self.$axis - other.$axis),* }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, n: i64) -> $point {
+                $point { $($axis: self.$axis * n),* }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($axis: -self.$axis),* }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+/// One of the four directions along the axes, in clockwise order from north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Whether the direction is north or south.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::North | Dir4::South)
+    }
+
+    /// The step one unit in this direction.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir4::North => Point2::new(0, -1),
+            Dir4::East => Point2::new(1, 0),
+            Dir4::South => Point2::new(0, 1),
+            Dir4::West => Point2::new(-1, 0),
+        }
+    }
+
+    /// The direction a puzzle writes as `U`, `D`, `L` or `R`, or draws as `^`, `v`, `<` or `>`.
+    pub fn from_glyph(glyph: char) -> Option<Dir4> {
+        match glyph {
+            'U' | '^' => Some(Dir4::North),
+            'R' | '>' => Some(Dir4::East),
+            'D' | 'v' => Some(Dir4::South),
+            'L' | '<' => Some(Dir4::West),
+            _ => None,
+        }
+    }
+
+    /// The arrow drawn for the direction: `^`, `>`, `v` or `<`.
+    pub fn arrow(self) -> char {
+        ['^', '>', 'v', '<'][self as usize]
+    }
+}
+
+impl Add<Dir4> for Point2 {
+    type Output = Point2;
+
+    fn add(self, dir: Dir4) -> Point2 {
+        self + dir.delta()
+    }
+}
+
+impl AddAssign<Dir4> for Point2 {
+    fn add_assign(&mut self, dir: Dir4) {
+        *self = *self + dir;
+    }
+}
+
+/// One of the eight directions along the axes and diagonals, in clockwise order from north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// The direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn anticlockwise.
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The step one unit in this direction, diagonals moving one unit along each axis.
+    pub fn delta(self) -> Point2 {
+        let (x, y) = match self {
+            Dir8::North => (0, -1),
+            Dir8::NorthEast => (1, -1),
+            Dir8::East => (1, 0),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (0, 1),
+            Dir8::SouthWest => (-1, 1),
+            Dir8::West => (-1, 0),
+            Dir8::NorthWest => (-1, -1),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Dir8 {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+/// A set of [`Dir4`]s in a byte, such as the directions something has already passed through a
+/// cell in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DirSet(u8);
+
+impl DirSet {
+    pub const EMPTY: DirSet = DirSet(0);
+
+    /// Adds `dir`, returning whether it wasn't already there.
+    pub fn insert(&mut self, dir: Dir4) -> bool {
+        let added = !self.contains(dir);
+        self.0 |= 1 << dir as u8;
+        added
+    }
+
+    /// Removes `dir`, returning whether it was there.
+    pub fn remove(&mut self, dir: Dir4) -> bool {
+        let removed = self.contains(dir);
+        self.0 &= !(1 << dir as u8);
+        removed
+    }
+
+    pub fn contains(self, dir: Dir4) -> bool {
+        self.0 & (1 << dir as u8) != 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// The directions in the set, clockwise from north.
+    pub fn iter(self) -> impl Iterator<Item = Dir4> {
+        Dir4::ALL.into_iter().filter(move |&d| self.contains(d))
+    }
+}
+
+impl FromIterator<Dir4> for DirSet {
+    fn from_iter<I: IntoIterator<Item = Dir4>>(dirs: I) -> DirSet {
+        let mut set = DirSet::EMPTY;
+        for dir in dirs {
+            set.insert(dir);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Dir4, Dir8, DirSet, Point2, Point3};
+
+    #[test]
+    fn test_points() {
+        let p = Point2::new(3, -4);
+        assert_eq!(Point2::new(4, -6), p + Point2::new(1, -2));
+        assert_eq!(Point2::new(-3, 4), -p);
+        assert_eq!(Point2::new(6, -8), p * 2);
+        assert_eq!(7, p.manhattan(Point2::ORIGIN));
+        assert_eq!(4, p.chebyshev(Point2::ORIGIN));
+        assert_eq!(Point2::new(1, -1), p.signum());
+        assert_eq!(Point2::new(3, -5), p + Dir4::North);
+
+        let mut q = Point3::new(1, 2, 3);
+        q -= Point3::new(2, 2, 2);
+        assert_eq!(Point3::new(-1, 0, 1), q);
+        assert_eq!(2, q.manhattan(Point3::ORIGIN));
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::East, Dir4::North.turn_right());
+        assert_eq!(Dir4::West, Dir4::North.turn_left());
+        assert_eq!(Dir4::North, Dir4::South.reverse());
+        assert!(Dir4::ALL.iter().all(|d| d.turn_left().turn_right() == *d));
+        assert_eq!(Dir8::NorthWest, Dir8::North.turn_left());
+        assert_eq!(Dir8::SouthWest, Dir8::NorthEast.reverse());
+        assert_eq!(Dir8::West, Dir4::West.into());
+        for dir in Dir4::ALL {
+            assert_eq!(dir.delta(), Dir8::from(dir).delta());
+            assert_eq!(Some(dir), Dir4::from_glyph(dir.arrow()));
+        }
+        let glyphs: Vec<_> = "UDLRx".chars().map(Dir4::from_glyph).collect();
+        assert_eq!(
+            vec![Some(Dir4::North), Some(Dir4::South), Some(Dir4::West), Some(Dir4::East), None],
+            glyphs
+        );
+    }
+
+    #[test]
+    fn test_dir_set() {
+        let mut set = DirSet::EMPTY;
+        assert!(set.is_empty());
+        assert!(set.insert(Dir4::West));
+        assert!(set.insert(Dir4::North));
+        assert!(!set.insert(Dir4::West));
+        assert_eq!(2, set.len());
+        assert_eq!(vec![Dir4::North, Dir4::West], set.iter().collect::<Vec<_>>());
+        assert!(set.remove(Dir4::North));
+        assert!(!set.contains(Dir4::North));
+        assert_eq!(set, [Dir4::West].into_iter().collect());
+    }
+}
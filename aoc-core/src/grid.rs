@@ -0,0 +1,484 @@
+//! A rectangle of cells, the shape of most of the puzzles' maps.
+//!
+//! Cells are addressed by `(row, column)` from the top left, so that positions sort in reading
+//! order. A day parses its map with [`Grid::parse`], turning each character into whatever cell
+//! type suits it, and keeps any bookkeeping of its own (distances, visits) in a second grid of
+//! the same size.
+
+use crate::geom::{Dir4, Point2};
+use crate::parse::{self, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell's row and column.
+pub type Pos = (usize, usize);
+
+/// The steps to the cells above, left, right and below, in reading order.
+const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The steps to the eight cells around, in reading order.
+const NEIGHBOURS8: [(isize, isize); 8] =
+    [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid whose characters are all in `cells`, converting each with `cell`.
+    pub fn parse(
+        input: &str,
+        cells: &str,
+        cell: impl FnMut(char) -> T,
+    ) -> Result<Grid<T>, ParseError> {
+        let rows = parse::grid(input, cells)?;
+        let width = rows[0].chars().count();
+        let cells = rows.iter().flat_map(|r| r.chars()).map(cell).collect();
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    /// A grid of the given rows, which must all be the same length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Grid<T> {
+        let (mut width, mut height, mut cells) = (0, 0, vec![]);
+        for row in rows {
+            cells.extend(row);
+            height += 1;
+            if height == 1 {
+                width = cells.len();
+            }
+            assert_eq!(height * width, cells.len(), "Row {} is the wrong length", height - 1);
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// A grid of `width` by `height` cells, all `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `pos` is inside the grid.
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos).then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// The cell at `(row, col)` on a map that repeats forever in every direction.
+    pub fn get_wrapped(&self, (row, col): (i64, i64)) -> &T {
+        let row = row.rem_euclid(self.height as i64) as usize;
+        let col = col.rem_euclid(self.width as i64) as usize;
+        &self[(row, col)]
+    }
+
+    /// The position `(drow, dcol)` away from `pos`, if it's inside the grid.
+    pub fn offset(&self, (row, col): Pos, (drow, dcol): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(drow)?, col.checked_add_signed(dcol)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The position one step from `pos` in direction `dir`, if it's inside the grid.
+    pub fn step(&self, pos: Pos, dir: Dir4) -> Option<Pos> {
+        let Point2 { x, y } = dir.delta();
+        self.offset(pos, (y as isize, x as isize))
+    }
+
+    /// The cells above, left, right and below `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4.into_iter().filter_map(move |d| self.offset(pos, d))
+    }
+
+    /// The cells around `pos`, diagonals included, that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8.into_iter().filter_map(move |d| self.offset(pos, d))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    /// Each row, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|r| self.row(r))
+    }
+
+    /// Each column, from the left, with its cells from the top.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|c| self.column(c))
+    }
+
+    /// Every position, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    /// Every cell and its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, in reading order, that is `value`.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// The positions of every cell that is `value`, in reading order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, c)| *c == value).map(|(p, _)| p)
+    }
+
+    /// The grid seen turned or flipped, without copying it.
+    pub fn view(&self, orientation: Orientation) -> View<'_, T> {
+        View {
+            grid: self,
+            orientation,
+        }
+    }
+
+    /// The grid seen turned or flipped, so that changes through the view change the grid.
+    pub fn view_mut(&mut self, orientation: Orientation) -> ViewMut<'_, T> {
+        ViewMut {
+            grid: self,
+            orientation,
+        }
+    }
+
+    /// A copy of the grid turned or flipped.
+    pub fn transformed(&self, orientation: Orientation) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let view = self.view(orientation);
+        Grid::from_rows(view.rows().map(|row| row.cloned()))
+    }
+
+    /// A grid of the same size, with each cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, width, height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, width, height))
+    }
+}
+
+/// One of the eight ways to turn or flip a grid over. Rotations are clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// Left to right, so each row is reversed.
+    FlipHorizontal,
+    /// Top to bottom, so the rows are in reverse order.
+    FlipVertical,
+    /// About the diagonal from the top left, so rows become columns.
+    Transpose,
+    /// About the diagonal from the top right.
+    AntiTranspose,
+}
+
+impl Orientation {
+    /// Whether the view's rows are the grid's columns, and whether the view's rows and columns
+    /// count backwards before that.
+    fn axes(self) -> (bool, bool, bool) {
+        match self {
+            Orientation::Identity => (false, false, false),
+            Orientation::Rotate90 => (true, false, true),
+            Orientation::Rotate180 => (false, true, true),
+            Orientation::Rotate270 => (true, true, false),
+            Orientation::FlipHorizontal => (false, false, true),
+            Orientation::FlipVertical => (false, true, false),
+            Orientation::Transpose => (true, false, false),
+            Orientation::AntiTranspose => (true, true, true),
+        }
+    }
+
+    /// The size of a `width` by `height` grid, turned.
+    fn size(self, width: usize, height: usize) -> (usize, usize) {
+        if self.axes().0 {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// The position in the grid of `(row, col)` in a view of it `width` by `height` cells.
+    fn to_grid(self, (row, col): Pos, width: usize, height: usize) -> Pos {
+        let (transpose, flip_rows, flip_cols) = self.axes();
+        let row = if flip_rows { height - 1 - row } else { row };
+        let col = if flip_cols { width - 1 - col } else { col };
+        if transpose {
+            (col, row)
+        } else {
+            (row, col)
+        }
+    }
+}
+
+/// A grid seen turned or flipped. Positions are the view's own, counting from its top left.
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    orientation: Orientation,
+}
+
+// Not derived, which would need `T: Copy`.
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.orientation.size(self.grid.width, self.grid.height).0
+    }
+
+    pub fn height(&self) -> usize {
+        self.orientation.size(self.grid.width, self.grid.height).1
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&'a T> {
+        let (width, height) = (self.width(), self.height());
+        let inside = pos.0 < height && pos.1 < width;
+        inside.then(|| &self.grid[self.orientation.to_grid(pos, width, height)])
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.width()).map(move |c| view.get((row, c)).unwrap())
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.height()).map(move |r| view.get((r, col)).unwrap())
+    }
+
+    /// Each row, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        let view = *self;
+        (0..self.height()).map(move |r| view.row(r))
+    }
+
+    /// Each column, from the left, with its cells from the top.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        let view = *self;
+        (0..self.width()).map(move |c| view.column(c))
+    }
+}
+
+impl<'a, T> Index<Pos> for View<'a, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &'a T {
+        let (width, height) = (self.width(), self.height());
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} view", pos, width, height))
+    }
+}
+
+/// A grid seen turned or flipped, through which its cells can be changed.
+pub struct ViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    orientation: Orientation,
+}
+
+impl<T> ViewMut<'_, T> {
+    pub fn width(&self) -> usize {
+        self.orientation.size(self.grid.width, self.grid.height).0
+    }
+
+    pub fn height(&self) -> usize {
+        self.orientation.size(self.grid.width, self.grid.height).1
+    }
+
+    fn to_grid(&self, pos: Pos) -> Pos {
+        let (width, height) = (self.width(), self.height());
+        assert!(
+            pos.0 < height && pos.1 < width,
+            "{:?} is outside the {}x{} view",
+            pos,
+            width,
+            height
+        );
+        self.orientation.to_grid(pos, width, height)
+    }
+}
+
+impl<T> Index<Pos> for ViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self.grid[self.to_grid(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for ViewMut<'_, T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let pos = self.to_grid(pos);
+        &mut self.grid[pos]
+    }
+}
+
+/// The grid as it would be written in a puzzle input, a line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Grid, Orientation};
+    use crate::geom::Dir4;
+
+    const MAP: &str = "#.S\n..#\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(MAP, ".#S", |c| c).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'S'), grid.get((0, 2)));
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!('#', grid[(1, 2)]);
+        assert_eq!(MAP, grid.to_string());
+        assert_eq!(
+            "line 2, column 1: expected one of \".#\", found 'S'",
+            Grid::parse("..\nS.\n", ".#", |c| c).err().unwrap().to_string()
+        );
+
+        let digits = Grid::parse("19\n05\n", "0123456789", |c| c as u8 - b'0').unwrap();
+        assert_eq!("19\n05\n", digits.to_string());
+        assert_eq!(vec![&[1, 9][..], &[0, 5][..]], digits.rows().collect::<Vec<_>>());
+        let columns: Vec<Vec<u8>> = digits.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(vec![vec![1, 0], vec![9, 5]], columns);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, 0);
+        let around = |pos| grid.neighbours4(pos).collect::<Vec<_>>();
+        assert_eq!(vec![(0, 1), (1, 0), (1, 2), (2, 1)], around((1, 1)));
+        assert_eq!(vec![(0, 1), (1, 0)], around((0, 0)));
+        assert_eq!(8, grid.neighbours8((1, 1)).count());
+        assert_eq!(vec![(1, 1), (1, 2), (2, 1)], grid.neighbours8((2, 2)).collect::<Vec<_>>());
+        assert_eq!(None, grid.offset((2, 2), (0, 1)));
+        assert_eq!(Some((0, 2)), grid.offset((2, 2), (-2, 0)));
+        assert_eq!(Some((1, 2)), grid.step((2, 2), Dir4::North));
+        assert_eq!(None, grid.step((2, 2), Dir4::East));
+    }
+
+    #[test]
+    fn test_find() {
+        let mut grid = Grid::parse(MAP, ".#S", |c| c).unwrap();
+        assert_eq!(Some((0, 2)), grid.find(&'S'));
+        assert_eq!(vec![(0, 0), (1, 2)], grid.find_all(&'#').collect::<Vec<_>>());
+        grid[(0, 2)] = '.';
+        assert_eq!(None, grid.find(&'S'));
+        assert_eq!(&'#', grid.get_wrapped((-1, -1)));
+        assert_eq!(&'#', grid.get_wrapped((2, 3)));
+        let walls = grid.map(|&c| c == '#');
+        assert_eq!(2, walls.iter().filter(|(_, &w)| w).count());
+    }
+
+    #[test]
+    fn test_orientations() {
+        let grid = Grid::parse("abc\ndef\n", "abcdef", |c| c).unwrap();
+        let turned = |o| grid.transformed(o).to_string();
+        assert_eq!("abc\ndef\n", turned(Orientation::Identity));
+        assert_eq!("da\neb\nfc\n", turned(Orientation::Rotate90));
+        assert_eq!("fed\ncba\n", turned(Orientation::Rotate180));
+        assert_eq!("cf\nbe\nad\n", turned(Orientation::Rotate270));
+        assert_eq!("cba\nfed\n", turned(Orientation::FlipHorizontal));
+        assert_eq!("def\nabc\n", turned(Orientation::FlipVertical));
+        assert_eq!("ad\nbe\ncf\n", turned(Orientation::Transpose));
+        assert_eq!("fc\neb\nda\n", turned(Orientation::AntiTranspose));
+
+        let view = grid.view(Orientation::Rotate90);
+        assert_eq!((2, 3), (view.width(), view.height()));
+        assert_eq!(Some(&'f'), view.get((2, 0)));
+        assert_eq!(None, view.get((0, 2)));
+        assert_eq!("eb", view.row(1).collect::<String>());
+        assert_eq!("abc", view.column(1).collect::<String>());
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut grid = Grid::filled(3, 2, '.');
+        let mut view = grid.view_mut(Orientation::Rotate270);
+        assert_eq!((2, 3), (view.width(), view.height()));
+        view[(0, 1)] = '#';
+        assert_eq!('#', view[(0, 1)]);
+        assert_eq!("...\n..#\n", grid.to_string());
+        assert_eq!(Grid::from_rows(["...".chars(), "..#".chars()]), grid);
+    }
+}
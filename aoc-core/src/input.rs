@@ -0,0 +1,153 @@
+//! Finding and reading puzzle inputs at runtime.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The files tried, in order, when no input is given explicitly.
+const DEFAULT_FILES: &[&str] = &["data/input.txt", "data/example.txt"];
+
+/// Where to read a puzzle input from.
+#[derive(Clone, Debug)]
+pub enum Source {
+    /// A specific file.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+    /// The first of `data/input.txt` and `data/example.txt` that exists in the day's directory.
+    Default,
+}
+
+impl Source {
+    /// Parses a command-line argument, where `-` means standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Reads the input for the day whose crate lives in `day_dir`.
+    pub fn read(&self, day_dir: &Path) -> Result<Input, Error> {
+        match self {
+            Source::File(path) => read_file(path.clone()),
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(|e| Error::Io(None, e))?;
+                Ok(Input { text, path: None })
+            }
+            Source::Default => {
+                let tried: Vec<PathBuf> = DEFAULT_FILES.iter().map(|f| day_dir.join(f)).collect();
+                match tried.iter().find(|p| p.is_file()) {
+                    Some(path) => read_file(path.clone()),
+                    None => Err(Error::NotFound(tried)),
+                }
+            }
+        }
+    }
+}
+
+/// A puzzle input, and the file it was read from (`None` for standard input).
+pub struct Input {
+    pub text: String,
+    pub path: Option<PathBuf>,
+}
+
+impl Input {
+    /// Whether this is one of the day's examples rather than the real puzzle input.
+    pub fn is_example(&self) -> bool {
+        self.path
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .is_some_and(|n| n.to_string_lossy().starts_with("example"))
+    }
+}
+
+/// Why an input could not be read.
+#[derive(Debug)]
+pub enum Error {
+    /// None of the default files exist.
+    NotFound(Vec<PathBuf>),
+    /// The file (or standard input, for `None`) could not be read.
+    Io(Option<PathBuf>, io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NotFound(tried) => {
+                write!(f, "No input found (tried ")?;
+                for (i, path) in tried.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", path.display())?;
+                }
+                write!(f, "); pass one with --input")
+            }
+            Error::Io(Some(path), e) => write!(f, "{}: {}", path.display(), e),
+            Error::Io(None, e) => write!(f, "stdin: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn read_file(path: PathBuf) -> Result<Input, Error> {
+    match fs::read_to_string(&path) {
+        Ok(text) => Ok(Input {
+            text,
+            path: Some(path),
+        }),
+        Err(e) => Err(Error::Io(Some(path), e)),
+    }
+}
+
+/// Reads the named file from the `data/` directory of the day whose crate lives in `day_dir`,
+/// panicking if it can't be read. Tests use this through the [`example!`](crate::example) macro.
+pub fn example(day_dir: &str, name: &str) -> String {
+    match read_file(Path::new(day_dir).join("data").join(name)) {
+        Ok(input) => input.text,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Reads one of the calling day's example inputs from its `data/` directory at runtime:
+/// `example!()` for `data/example.txt`, or `example!("example2.txt")` for any other file.
+#[macro_export]
+macro_rules! example {
+    () => {
+        $crate::example!("example.txt")
+    };
+    ($name:expr) => {
+        $crate::input::example(env!("CARGO_MANIFEST_DIR"), $name)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Error, Source};
+    use std::path::Path;
+
+    #[test]
+    fn test_default_not_found() {
+        let dir = Path::new("/nonexistent");
+        match Source::Default.read(dir) {
+            Err(Error::NotFound(tried)) => assert_eq!(
+                vec![dir.join("data/input.txt"), dir.join("data/example.txt")],
+                tried
+            ),
+            _ => panic!("Expected NotFound"),
+        }
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert!(matches!(Source::from_arg("-"), Source::Stdin));
+        assert!(matches!(Source::from_arg("in.txt"), Source::File(p) if p == Path::new("in.txt")));
+    }
+}
@@ -0,0 +1,72 @@
+//! Code shared by every day's solution.
+
+pub mod debug;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod lint;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod stats;
+
+use lint::Shape;
+use parse::ParseError;
+use std::env;
+use std::fmt::Display;
+use std::thread;
+
+/// The environment variable that limits how many threads a solution may use. `aoc all` sets it
+/// for the parts it runs side by side, so that between them they don't ask for more threads than
+/// there are CPUs.
+pub const THREADS_VAR: &str = "AOC_THREADS";
+
+/// How many threads a solution may split its work between: `AOC_THREADS` if it's a positive
+/// number, or otherwise one for each CPU.
+pub fn threads() -> usize {
+    let limit = env::var(THREADS_VAR).ok().and_then(|n| n.parse().ok());
+    limit
+        .filter(|&n| n > 0)
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+}
+
+/// A solution to a single day's puzzle.
+///
+/// The puzzle input is parsed once, and the parsed form is shared by both parts.
+pub trait Solution {
+    /// The parsed puzzle input, which may borrow from the input text.
+    type Input<'a>;
+    /// The answer to part 1.
+    type Part1: Display;
+    /// The answer to part 2.
+    type Part2: Display;
+
+    /// The layout of the puzzle input, which `aoc lint` checks it against.
+    const SHAPE: Shape = Shape::Lines;
+
+    /// Parses the puzzle input, reporting where it is malformed.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+}
+
+#[cfg(test)]
+mod test {
+    use super::{threads, THREADS_VAR};
+    use std::env;
+
+    #[test]
+    fn test_threads() {
+        env::set_var(THREADS_VAR, "3");
+        assert_eq!(3, threads());
+        env::set_var(THREADS_VAR, "0");
+        let default = threads();
+        assert!(default >= 1);
+        env::set_var(THREADS_VAR, "lots");
+        assert_eq!(default, threads());
+        env::remove_var(THREADS_VAR);
+        assert_eq!(default, threads());
+    }
+}
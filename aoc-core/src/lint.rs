@@ -0,0 +1,252 @@
+//! Checks puzzle inputs for the whitespace problems that creep in when they're copied around,
+//! and fixes the ones that can be fixed safely.
+//!
+//! Each day declares the [`Shape`] of its input in [`Solution::SHAPE`](crate::Solution::SHAPE),
+//! which decides what counts as a problem: a blank line is expected between blocks but not in the
+//! middle of a grid, and trailing spaces matter in a drawing but nowhere else.
+
+use std::fmt;
+
+/// The overall layout of a day's input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shape {
+    /// One record per line, with no blank lines.
+    Lines,
+    /// A single line.
+    SingleLine,
+    /// A rectangular grid of characters.
+    Grid,
+    /// Sections separated by blank lines.
+    Blocks,
+    /// Rectangular grids separated by blank lines, each of which may have its own size.
+    Grids,
+    /// Text whose layout matters, such as a drawing padded with spaces. Only the encoding and line
+    /// endings are checked.
+    Text,
+}
+
+impl Shape {
+    fn name(self) -> &'static str {
+        match self {
+            Shape::Lines => "lines",
+            Shape::SingleLine => "single line",
+            Shape::Grid => "grid",
+            Shape::Blocks => "blank-line-separated blocks",
+            Shape::Grids => "blank-line-separated grids",
+            Shape::Text => "text",
+        }
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Something wrong with the layout of an input.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Issue {
+    /// The input starts with a byte order mark.
+    Bom,
+    /// Lines end with `\r\n`: how many, and the first.
+    Crlf { count: usize, first: usize },
+    /// Lines end with spaces or tabs: how many, and the first.
+    TrailingSpace { count: usize, first: usize },
+    /// The last line has no newline.
+    MissingFinalNewline,
+    /// There are blank lines after the last line.
+    TrailingBlankLines(usize),
+    /// A blank line where the shape has none.
+    BlankLine(usize),
+    /// A line after the first, in a single-line input.
+    ExtraLine(usize),
+    /// A grid row whose width differs from the first row of its grid.
+    Ragged { line: usize, width: usize, expected: usize },
+}
+
+impl Issue {
+    /// Whether [`normalise`] fixes this issue.
+    pub fn fixable(&self) -> bool {
+        matches!(
+            self,
+            Issue::Bom
+                | Issue::Crlf { .. }
+                | Issue::TrailingSpace { .. }
+                | Issue::MissingFinalNewline
+                | Issue::TrailingBlankLines(_)
+        )
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let more = |count: usize| match count {
+            1 => String::new(),
+            n => format!(" (and {} more)", n - 1),
+        };
+        match self {
+            Issue::Bom => write!(f, "starts with a byte order mark"),
+            Issue::Crlf { count, first } => {
+                write!(f, "line {}: CRLF line ending{}", first, more(*count))
+            }
+            Issue::TrailingSpace { count, first } => {
+                write!(f, "line {}: trailing whitespace{}", first, more(*count))
+            }
+            Issue::MissingFinalNewline => write!(f, "no newline at the end"),
+            Issue::TrailingBlankLines(n) => write!(f, "{} blank line(s) at the end", n),
+            Issue::BlankLine(line) => write!(f, "line {}: unexpected blank line", line),
+            Issue::ExtraLine(line) => write!(f, "line {}: more than one line", line),
+            Issue::Ragged {
+                line,
+                width,
+                expected,
+            } => write!(f, "line {}: row is {} wide, expected {}", line, width, expected),
+        }
+    }
+}
+
+/// Finds the problems with `input`, given the shape it should have.
+pub fn lint(input: &str, shape: Shape) -> Vec<Issue> {
+    let mut issues = vec![];
+    let text = match input.strip_prefix('\u{feff}') {
+        Some(text) => {
+            issues.push(Issue::Bom);
+            text
+        }
+        None => input,
+    };
+    if text.is_empty() {
+        return issues;
+    }
+
+    // Split on `\n` alone, so CRLF endings can be seen.
+    let raw: Vec<&str> = text.strip_suffix('\n').unwrap_or(text).split('\n').collect();
+    let crlf: Vec<usize> = (1..)
+        .zip(&raw)
+        .filter(|(_, l)| l.ends_with('\r'))
+        .map(|(n, _)| n)
+        .collect();
+    if let Some(&first) = crlf.first() {
+        issues.push(Issue::Crlf {
+            count: crlf.len(),
+            first,
+        });
+    }
+    let lines: Vec<&str> = raw.iter().map(|l| l.strip_suffix('\r').unwrap_or(l)).collect();
+    if shape != Shape::Text {
+        let trailing: Vec<usize> = (1..)
+            .zip(&lines)
+            .filter(|(_, l)| l.ends_with([' ', '\t']))
+            .map(|(n, _)| n)
+            .collect();
+        if let Some(&first) = trailing.first() {
+            issues.push(Issue::TrailingSpace {
+                count: trailing.len(),
+                first,
+            });
+        }
+    }
+    if !text.ends_with('\n') {
+        issues.push(Issue::MissingFinalNewline);
+    }
+    let blank_at_end = lines.iter().rev().take_while(|l| l.trim().is_empty()).count();
+    if blank_at_end > 0 {
+        issues.push(Issue::TrailingBlankLines(blank_at_end));
+    }
+    let body = &lines[..lines.len() - blank_at_end];
+
+    match shape {
+        Shape::Lines | Shape::Grid | Shape::SingleLine => {
+            for (n, l) in (1..).zip(body) {
+                if l.trim().is_empty() {
+                    issues.push(Issue::BlankLine(n));
+                } else if shape == Shape::SingleLine && n > 1 {
+                    issues.push(Issue::ExtraLine(n));
+                }
+            }
+        }
+        _ => {}
+    }
+    if matches!(shape, Shape::Grid | Shape::Grids) {
+        let mut expected = None;
+        for (n, l) in (1..).zip(body) {
+            let l = l.trim_end();
+            if l.is_empty() {
+                expected = None;
+                continue;
+            }
+            let width = l.chars().count();
+            match expected {
+                None => expected = Some(width),
+                Some(expected) if width != expected => issues.push(Issue::Ragged {
+                    line: n,
+                    width,
+                    expected,
+                }),
+                _ => {}
+            }
+        }
+    }
+    issues
+}
+
+/// Fixes the problems with `input` that can be fixed without guessing: removes a byte order mark,
+/// trailing whitespace (unless the shape is [`Shape::Text`]) and blank lines at the end, converts
+/// CRLF line endings to `\n`, and makes sure the last line ends with a newline. Blank lines in the
+/// middle, extra lines and ragged grid rows are left for a person to sort out.
+pub fn normalise(input: &str, shape: Shape) -> String {
+    let text = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut lines: Vec<&str> = text
+        .lines()
+        .map(|l| if shape == Shape::Text { l } else { l.trim_end_matches([' ', '\t']) })
+        .collect();
+    while lines.last().is_some_and(|l| l.trim().is_empty()) {
+        lines.pop();
+    }
+    let mut out = lines.join("\n");
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lint, normalise, Issue, Shape};
+
+    #[test]
+    fn test_clean() {
+        assert_eq!(Vec::<Issue>::new(), lint("#.\n.#\n", Shape::Grid));
+        assert_eq!(Vec::<Issue>::new(), lint("1\n2\n\n3\n", Shape::Blocks));
+        assert_eq!(Vec::<Issue>::new(), lint("    [D]    \n", Shape::Text));
+    }
+
+    #[test]
+    fn test_fixable() {
+        let input = "\u{feff}rn=1,cm-  \r\n\r\n";
+        assert_eq!(
+            vec![
+                Issue::Bom,
+                Issue::Crlf { count: 2, first: 1 },
+                Issue::TrailingSpace { count: 1, first: 1 },
+                Issue::TrailingBlankLines(1),
+            ],
+            lint(input, Shape::SingleLine)
+        );
+        assert_eq!("rn=1,cm-\n", normalise(input, Shape::SingleLine));
+        assert_eq!(vec![Issue::MissingFinalNewline], lint("1\n2", Shape::Lines));
+        assert_eq!("1\n2\n", normalise("1\n2", Shape::Lines));
+    }
+
+    #[test]
+    fn test_shape() {
+        assert_eq!(
+            vec![Issue::BlankLine(2), Issue::Ragged { line: 4, width: 3, expected: 2 }],
+            lint("#.\n\n.#\n#..\n", Shape::Grid)
+        );
+        assert_eq!(Vec::<Issue>::new(), lint("#.\n.#\n\n#..\n..#\n", Shape::Grids));
+        assert_eq!(vec![Issue::ExtraLine(2)], lint("1,2\n3\n", Shape::SingleLine));
+        assert_eq!("    [D]    \n", normalise("    [D]    \n", Shape::Text));
+    }
+}
@@ -0,0 +1,389 @@
+//! Helpers for parsing puzzle inputs, reporting where the input is malformed.
+//!
+//! Inputs are read a line at a time through [`Line`], which keeps track of the line number and
+//! column so that any error can point at the offending text. Lines are split as by
+//! [`str::lines`], so CRLF line endings are handled transparently.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A problem with the puzzle input, and where it was found.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// The year and day being parsed, if known. The parsers themselves don't know which day
+    /// they belong to, so this is filled in by whoever calls them.
+    pub day: Option<(u16, u8)>,
+    /// The line number, counting from 1.
+    pub line: usize,
+    /// The column, in characters, counting from 1.
+    pub column: usize,
+    /// A description of what should have been there.
+    pub expected: String,
+    /// A description of what was there instead.
+    pub found: String,
+}
+
+impl ParseError {
+    /// An error at the end of an input with `lines` lines.
+    pub fn end_of_input(lines: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line: lines + 1,
+            column: 1,
+            expected: expected.into(),
+            found: "end of input".to_string(),
+        }
+    }
+
+    /// Records which day the error comes from.
+    pub fn for_day(self, year: u16, day: u8) -> Self {
+        ParseError {
+            day: Some((year, day)),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((year, day)) = self.day {
+            write!(f, "{}/{:02}: ", year, day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// A single line of the input, with a cursor marking how much of it has been parsed.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    number: usize,
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Line<'a> {
+    /// The given line, which is line `number` (counting from 1) of the input.
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Line {
+            number,
+            text,
+            pos: 0,
+        }
+    }
+
+    /// The line number, counting from 1.
+    pub fn line_number(&self) -> usize {
+        self.number
+    }
+
+    /// The whole text of the line, without its line ending.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The part of the line that hasn't been parsed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// Whether the whole line has been parsed.
+    pub fn at_end(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.pos, expected)
+    }
+
+    /// An error at the given byte offset into the line, or at the start of the character that
+    /// the offset falls inside.
+    pub fn error_at(&self, pos: usize, expected: impl Into<String>) -> ParseError {
+        let mut pos = pos.min(self.text.len());
+        while !self.text.is_char_boundary(pos) {
+            pos -= 1;
+        }
+        let found = match self.text[pos..].chars().next() {
+            Some(c) => format!("{:?}", c),
+            None => "end of line".to_string(),
+        };
+        ParseError {
+            day: None,
+            line: self.number,
+            column: self.text[..pos].chars().count() + 1,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// Consumes `tag` if the line continues with it, returning whether it did.
+    pub fn eat(&mut self, tag: &str) -> bool {
+        if self.rest().starts_with(tag) {
+            self.pos += tag.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes `tag`, which must come next.
+    pub fn tag(&mut self, tag: &str) -> Result<()> {
+        if self.eat(tag) {
+            Ok(())
+        } else {
+            Err(self.error(format!("{:?}", tag)))
+        }
+    }
+
+    /// Skips over any spaces.
+    pub fn spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches(' ').len();
+    }
+
+    /// Consumes the longest run of characters matching `pred`, which must not be empty.
+    pub fn take_while(
+        &mut self,
+        pred: impl Fn(char) -> bool,
+        expected: impl Into<String>,
+    ) -> Result<&'a str> {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error(expected));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Consumes everything up to the next `delim`, and the delimiter itself.
+    pub fn take_until(&mut self, delim: &str) -> Result<&'a str> {
+        let rest = self.rest();
+        match rest.find(delim) {
+            Some(i) => {
+                self.pos += i + delim.len();
+                Ok(&rest[..i])
+            }
+            None => Err(self.error_at(self.text.len(), format!("{:?}", delim))),
+        }
+    }
+
+    /// Consumes the rest of the line, which must not be empty.
+    pub fn take_rest(&mut self, expected: impl Into<String>) -> Result<&'a str> {
+        self.take_while(|_| true, expected)
+    }
+
+    /// Consumes a word made of letters, digits and underscores.
+    pub fn word(&mut self) -> Result<&'a str> {
+        self.take_while(|c| c.is_ascii_alphanumeric() || c == '_', "a word")
+    }
+
+    /// Consumes a single character, which must be one of `chars`.
+    pub fn one_of(&mut self, chars: &str) -> Result<char> {
+        match self.rest().chars().next() {
+            Some(c) if chars.contains(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.error(format!("one of {:?}", chars))),
+        }
+    }
+
+    /// Consumes a decimal number, with an optional leading `-`.
+    pub fn number<T: FromStr>(&mut self) -> Result<T> {
+        let start = self.pos;
+        self.eat("-");
+        let digits = self.take_while(|c| c.is_ascii_digit(), "a number");
+        let parsed = digits.ok().and_then(|_| self.text[start..self.pos].parse().ok());
+        parsed.ok_or_else(|| {
+            let e = self.error_at(start, "a number");
+            self.pos = start;
+            e
+        })
+    }
+
+    /// Consumes one or more items parsed by `item`, separated by `sep`.
+    pub fn list<T>(
+        &mut self,
+        sep: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.eat(sep) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Parses the rest of the line with `f`, checking that nothing is left over.
+    pub fn parse<T>(mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let item = f(&mut self)?;
+        self.end()?;
+        Ok(item)
+    }
+
+    /// Checks that the whole line has been parsed.
+    pub fn end(&self) -> Result<()> {
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+/// The lines of the input.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// The lines of the input, grouped into blocks separated by blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![vec![]];
+    for line in lines(input) {
+        if line.text().is_empty() {
+            blocks.push(vec![]);
+        } else {
+            blocks.last_mut().unwrap().push(line);
+        }
+    }
+    blocks.retain(|b| !b.is_empty());
+    blocks
+}
+
+/// Parses every line of the input with `f`.
+pub fn each_line<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&mut Line<'a>) -> Result<T>,
+) -> Result<Vec<T>> {
+    lines(input).map(|line| line.parse(&mut f)).collect()
+}
+
+/// Parses an input made of a single line with `f`.
+pub fn single_line<'a, T>(
+    input: &'a str,
+    f: impl FnOnce(&mut Line<'a>) -> Result<T>,
+) -> Result<T> {
+    let mut lines = lines(input);
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(0, "a line"))?;
+    let item = line.parse(f)?;
+    match lines.next() {
+        Some(extra) => Err(extra.error("end of input")),
+        None => Ok(item),
+    }
+}
+
+/// The rows of a rectangular grid, checking that every row is the same width and only contains
+/// the given characters.
+pub fn grid<'a>(input: &'a str, cells: &str) -> Result<Vec<&'a str>> {
+    let rows: Vec<Line> = lines(input).collect();
+    let Some(first) = rows.first() else {
+        return Err(ParseError::end_of_input(0, "a grid"));
+    };
+    let width = first.text().len();
+    if width == 0 {
+        return Err(first.error("a grid row"));
+    }
+    for row in &rows {
+        if let Some(i) = row.text().find(|c| !cells.contains(c)) {
+            return Err(row.error_at(i, format!("one of {:?}", cells)));
+        }
+        if row.text().len() != width {
+            let pos = row.text().len().min(width);
+            return Err(row.error_at(pos, format!("a row of {} cells", width)));
+        }
+    }
+    Ok(rows.iter().map(|r| r.text()).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{blocks, each_line, grid, Line, ParseError};
+
+    fn error(line: usize, column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            day: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_line() {
+        let mut line = Line::new(3, "move 12 from -3 to x");
+        assert_eq!(Ok(()), line.tag("move "));
+        assert_eq!(Ok(12), line.number::<u32>());
+        line.spaces();
+        assert_eq!(Ok("from"), line.take_until(" "));
+        assert_eq!(Ok(-3), line.number::<i32>());
+        assert_eq!(Ok(" "), line.take_until("to"));
+        line.spaces();
+        assert_eq!(Err(error(3, 20, "a number", "'x'")), line.number::<u32>());
+        assert_eq!(Ok("x"), line.word());
+        assert_eq!(Ok(()), line.end());
+    }
+
+    #[test]
+    fn test_error_inside_char() {
+        let line = Line::new(1, "aé!");
+        assert_eq!(error(1, 2, "a letter", "'é'"), line.error_at(2, "a letter"));
+        assert_eq!(error(1, 3, "a letter", "'!'"), line.error_at(3, "a letter"));
+        assert_eq!(error(1, 4, "a letter", "end of line"), line.error_at(9, "a letter"));
+    }
+
+    #[test]
+    fn test_number_out_of_range() {
+        let mut line = Line::new(1, "-1");
+        assert_eq!(Err(error(1, 1, "a number", "'-'")), line.number::<u32>());
+        assert_eq!("-1", line.rest());
+    }
+
+    #[test]
+    fn test_list() {
+        let mut line = Line::new(1, "1,2,3;");
+        assert_eq!(Ok(vec![1, 2, 3]), line.list(",", |l| l.number::<u8>()));
+        assert_eq!(Err(error(1, 6, "end of line", "';'")), line.end());
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = "1 2\r\n3 4\r\n\r\n5 6\r\n";
+        let numbers = |l: &mut Line| l.list(" ", |l| l.number::<u8>());
+        assert_eq!(
+            Err(error(3, 1, "a number", "end of line")),
+            each_line(input, numbers)
+        );
+        assert_eq!(
+            vec![2, 1],
+            blocks(input).iter().map(|b| b.len()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(Ok(vec!["#.", ".#"]), grid("#.\r\n.#\r\n", "#."));
+        assert_eq!(Err(error(2, 2, "one of \"#.\"", "'x'")), grid("#.\n.x", "#."));
+        assert_eq!(
+            Err(error(2, 2, "a row of 2 cells", "end of line")),
+            grid("#.\n.", "#.")
+        );
+        assert_eq!(Err(error(2, 3, "a row of 2 cells", "'.'")), grid("#.\n.#.", "#."));
+        assert_eq!(Err(error(1, 1, "a grid row", "end of line")), grid("\n", "#."));
+        assert_eq!(Err(error(1, 1, "a grid row", "end of line")), grid("\n#.\n", "#."));
+    }
+}
@@ -0,0 +1,82 @@
+//! Pictures of a solution at work, for the runner to save as images or animations.
+//!
+//! A day that can show what it's doing draws a [`Frame`] at each interesting step: a grid of
+//! cells, each holding an index into the day's palette. The runner decides what to do with them,
+//! so the days never need to know about image formats.
+
+/// A colour, as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// A grid of cells, each coloured from a palette of up to 256 colours.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    palette: &'static [Rgb],
+    cells: Vec<u8>,
+}
+
+impl Frame {
+    /// A frame of the given size, filled with the palette's first colour.
+    pub fn new(width: usize, height: usize, palette: &'static [Rgb]) -> Self {
+        assert!(
+            (1..=256).contains(&palette.len()),
+            "Palette must have between 1 and 256 colours"
+        );
+        Frame {
+            width,
+            height,
+            palette,
+            cells: vec![0; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn palette(&self) -> &'static [Rgb] {
+        self.palette
+    }
+
+    /// The palette index of the cell in column `x` of row `y`, counting from the top left.
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.cells[y * self.width + x]
+    }
+
+    /// Colours the cell in column `x` of row `y` with the palette's colour `colour`.
+    pub fn set(&mut self, x: usize, y: usize, colour: u8) {
+        debug_assert!((colour as usize) < self.palette.len(), "No colour {}", colour);
+        self.cells[y * self.width + x] = colour;
+    }
+
+    /// The colour of the cell in column `x` of row `y`.
+    pub fn rgb(&self, x: usize, y: usize) -> Rgb {
+        self.palette[self.get(x, y) as usize]
+    }
+
+    /// The palette indices of each row, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Frame, Rgb};
+
+    const PALETTE: &[Rgb] = &[[0, 0, 0], [255, 255, 255]];
+
+    #[test]
+    fn test_frame() {
+        let mut frame = Frame::new(3, 2, PALETTE);
+        frame.set(2, 1, 1);
+        assert_eq!(1, frame.get(2, 1));
+        assert_eq!([255, 255, 255], frame.rgb(2, 1));
+        assert_eq!(vec![&[0, 0, 0][..], &[0, 0, 1][..]], frame.rows().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,325 @@
+//! Searches through the states of a puzzle, from one or more starts to a goal.
+//!
+//! A day describes its states by their type and a function giving each state's successors (with
+//! the cost of moving to each, for the weighted searches). The search returns what it
+//! [`Explored`]: the cheapest cost it found to each state, and the way back to a start from any
+//! it settled. It reports how many states it expanded, and how long its queue grew, to
+//! [`stats`](crate::stats).
+
+use crate::stats;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+struct Node<S> {
+    cost: u64,
+    /// The state this was reached from, or `None` for a start.
+    parent: Option<S>,
+    /// Whether `cost` is known to be the cheapest.
+    settled: bool,
+}
+
+/// The states a search reached, and the cheapest ways it found to them.
+pub struct Explored<S> {
+    nodes: HashMap<S, Node<S>>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Explored<S> {
+    fn new() -> Self {
+        Explored {
+            nodes: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The goal state the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of the cheapest path to the goal, if one was found.
+    pub fn goal_cost(&self) -> Option<u64> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// The cost of the cheapest path to `state`, if the search settled it.
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        self.nodes.get(state).filter(|n| n.settled).map(|n| n.cost)
+    }
+
+    /// The cheapest path from a start to `state`, both included, if the search settled it.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.cost(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(parent) = &self.nodes[path.last().unwrap()].parent {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The cheapest path from a start to the goal, if one was found.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Every state the search settled, with its cost, in no particular order.
+    pub fn settled(&self) -> impl Iterator<Item = (&S, u64)> {
+        self.nodes.iter().filter(|(_, n)| n.settled).map(|(s, n)| (s, n.cost))
+    }
+
+    /// Every state the search reached but hadn't settled when it stopped.
+    pub fn queued(&self) -> impl Iterator<Item = &S> {
+        self.nodes.iter().filter(|(_, n)| !n.settled).map(|(s, _)| s)
+    }
+
+    /// Records reaching `state` at `cost`, returning whether that's cheaper than any way before.
+    fn reach(&mut self, state: &S, cost: u64, parent: Option<&S>) -> bool {
+        match self.nodes.get(state) {
+            Some(n) if n.settled || n.cost <= cost => false,
+            _ => {
+                let parent = parent.cloned();
+                let node = Node {
+                    cost,
+                    parent,
+                    settled: false,
+                };
+                self.nodes.insert(state.clone(), node);
+                true
+            }
+        }
+    }
+}
+
+/// Searches outwards from `starts` a step at a time, stopping at the first state that is a
+/// `goal`, or once everything reachable has been settled. Each time the search moves on to
+/// states further away, and when it finishes, `progress` is shown what it has explored.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    mut progress: impl FnMut(&Explored<S>),
+) -> Explored<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut explored = Explored::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if explored.reach(&start, 0, None) {
+            queue.push_back(start);
+        }
+    }
+    let (mut expanded, mut max_queued, mut level) = (0, queue.len() as u64, 0);
+
+    while let Some(state) = queue.pop_front() {
+        let node = explored.nodes.get_mut(&state).unwrap();
+        let cost = node.cost;
+        node.settled = true;
+        if cost > level {
+            progress(&explored);
+            level = cost;
+        }
+        if goal(&state) {
+            explored.goal = Some(state);
+            break;
+        }
+        expanded += 1;
+        for next in successors(&state) {
+            if explored.reach(&next, cost + 1, Some(&state)) {
+                queue.push_back(next);
+            }
+        }
+        max_queued = max_queued.max(queue.len() as u64);
+    }
+
+    progress(&explored);
+    stats::count("states expanded", expanded);
+    stats::max("max queue length", max_queued);
+    explored
+}
+
+/// Searches from `starts` for the cheapest path to a `goal`, where `successors` gives each
+/// state that can be moved to next and the cost of moving there. Without a goal, it settles
+/// everything reachable. Each time the search moves on to a greater cost, and when it finishes,
+/// `progress` is shown what it has explored.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+    progress: impl FnMut(&Explored<S>),
+) -> Explored<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, goal, progress)
+}
+
+/// An entry in the queue of a best-first search, ordered so that the heap pops the lowest
+/// priority first, and the earliest pushed of those.
+struct Queued<S> {
+    priority: u64,
+    order: u64,
+    cost: u64,
+    state: S,
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.priority, other.order).cmp(&(self.priority, self.order))
+    }
+}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+/// Searches like [`dijkstra`], but looking first at the states that `heuristic` estimates are
+/// closest to a goal. The heuristic must be zero at a goal, and never more than the cost of a
+/// move plus its estimate from where the move leads, or the path found may not be the cheapest.
+/// `progress` is shown what has been explored each time the estimated total cost grows.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut goal: impl FnMut(&S) -> bool,
+    mut progress: impl FnMut(&Explored<S>),
+) -> Explored<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut explored = Explored::new();
+    let mut queue = BinaryHeap::new();
+    let mut order = 0;
+    for state in starts {
+        if explored.reach(&state, 0, None) {
+            let priority = heuristic(&state);
+            queue.push(Queued {
+                priority,
+                order,
+                cost: 0,
+                state,
+            });
+            order += 1;
+        }
+    }
+    let (mut expanded, mut skipped, mut max_queued, mut level) = (0, 0, queue.len() as u64, 0);
+
+    while let Some(Queued {
+        priority,
+        cost,
+        state,
+        ..
+    }) = queue.pop()
+    {
+        // A cheaper way here was queued after this one, and has already been expanded.
+        if explored.nodes[&state].settled || explored.nodes[&state].cost < cost {
+            skipped += 1;
+            continue;
+        }
+        if priority > level {
+            progress(&explored);
+            level = priority;
+        }
+        explored.nodes.get_mut(&state).unwrap().settled = true;
+        if goal(&state) {
+            explored.goal = Some(state);
+            break;
+        }
+        expanded += 1;
+        for (next, step) in successors(&state) {
+            if explored.reach(&next, cost + step, Some(&state)) {
+                let priority = cost + step + heuristic(&next);
+                queue.push(Queued {
+                    priority,
+                    order,
+                    cost: cost + step,
+                    state: next,
+                });
+                order += 1;
+            }
+        }
+        max_queued = max_queued.max(queue.len() as u64);
+    }
+
+    progress(&explored);
+    stats::count("states expanded", expanded);
+    stats::count("settled states skipped", skipped);
+    stats::max("max heap size", max_queued);
+    explored
+}
+
+#[cfg(test)]
+mod test {
+    use super::{astar, bfs, dijkstra};
+    use crate::stats::{self, Stat};
+
+    /// Moves along a line of numbers, a step of one costing 3 and a jump of three costing 5.
+    fn moves(&n: &i64) -> Vec<(i64, u64)> {
+        vec![(n - 1, 3), (n + 1, 3), (n + 3, 5)]
+    }
+
+    #[test]
+    fn test_bfs() {
+        let explored = bfs([0], |&n| [n + 1, n + 3], |&n| n == 7, |_| {});
+        assert_eq!(Some(&7), explored.goal());
+        assert_eq!(Some(3), explored.goal_cost());
+        assert_eq!(Some(vec![0, 1, 4, 7]), explored.goal_path());
+        assert_eq!(Some(1), explored.cost(&3));
+        assert!(explored.queued().count() > 0);
+
+        // Without a goal, everything reachable is settled, from the nearest start.
+        let line = |&n: &i64| (0..=10).filter(move |m| (n - m).abs() == 1);
+        let explored = bfs([0, 10], line, |_| false, |_| {});
+        assert_eq!(11, explored.settled().count());
+        assert_eq!(Some(4), explored.cost(&4));
+        assert_eq!(Some(2), explored.cost(&8));
+        assert_eq!(Some(vec![10]), explored.path(&10));
+        assert_eq!(None, explored.goal_cost());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let mut levels = vec![];
+        let (explored, stats) = stats::collect(|| {
+            dijkstra([0], moves, |&n| n == 6, |e| levels.push(e.settled().count()))
+        });
+        // Two jumps cost 10, which beats six steps, or a step and a jump either way round.
+        assert_eq!(Some(10), explored.goal_cost());
+        assert_eq!(Some(vec![0, 3, 6]), explored.goal_path());
+        assert!(levels.windows(2).all(|w| w[0] <= w[1]));
+        // Everything cheaper than 10 is expanded first: 0, -1, 1, 3, -2, 2, 4 and -3.
+        assert_eq!(Some(&Stat::Count(8)), stats.get("states expanded"));
+        assert_eq!(9, explored.settled().count());
+    }
+
+    #[test]
+    fn test_astar() {
+        let heuristic = |&n: &i64| (7 - n).unsigned_abs() * 5 / 3;
+        let (explored, stats) =
+            stats::collect(|| astar([0], moves, heuristic, |&n| n == 7, |_| {}));
+        assert_eq!(Some(13), explored.goal_cost());
+        let Some(&Stat::Count(guided)) = stats.get("states expanded") else {
+            panic!("No count of states expanded");
+        };
+        let (_, stats) = stats::collect(|| dijkstra([0], moves, |&n| n == 7, |_| {}));
+        let Some(&Stat::Count(unguided)) = stats.get("states expanded") else {
+            panic!("No count of states expanded");
+        };
+        assert!(guided < unguided);
+    }
+}
@@ -0,0 +1,243 @@
+//! Counters that a search reports into, to show why it's slow: how many states it expanded, how
+//! big its queue grew, how many attempts it needed.
+//!
+//! Nothing is kept unless the runner is [`collect`]ing on the current thread, so a solution pays
+//! for no more than a thread-local check each time it reports. Searches should keep their own
+//! tallies in plain variables while they run and report them once, when they finish.
+
+use std::cell::RefCell;
+use std::fmt;
+
+thread_local! {
+    static STATS: RefCell<Option<Stats>> = const { RefCell::new(None) };
+}
+
+/// A count of values by their order of magnitude, with their total, smallest and largest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    /// The number of values whose bit length is the index: 0, 1, 2..=3, 4..=7 and so on, up to
+    /// the largest value's.
+    buckets: Vec<u64>,
+    count: u64,
+    sum: u128,
+    min: u64,
+    max: u64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Histogram {
+            buckets: vec![],
+            count: 0,
+            sum: 0,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+}
+
+impl Histogram {
+    pub fn new() -> Self {
+        Histogram::default()
+    }
+
+    pub fn add(&mut self, value: u64) {
+        let bucket = (u64::BITS - value.leading_zeros()) as usize;
+        if self.buckets.len() <= bucket {
+            self.buckets.resize(bucket + 1, 0);
+        }
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.sum += value as u128;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn merge(&mut self, other: &Histogram) {
+        if self.buckets.len() < other.buckets.len() {
+            self.buckets.resize(other.buckets.len(), 0);
+        }
+        for (bucket, n) in self.buckets.iter_mut().zip(&other.buckets) {
+            *bucket += n;
+        }
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The smallest value, or `None` if there aren't any.
+    pub fn min(&self) -> Option<u64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// The largest value, or `None` if there aren't any.
+    pub fn max(&self) -> Option<u64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum as f64 / self.count as f64)
+    }
+
+    /// The range of values each non-empty bucket holds, inclusive, and how many it has.
+    pub fn buckets(&self) -> impl Iterator<Item = (u64, u64, u64)> + '_ {
+        self.buckets.iter().enumerate().filter(|b| *b.1 > 0).map(|(i, &n)| match i {
+            0 => (0, 0, n),
+            _ => (1 << (i - 1), u64::MAX >> (64 - i), n),
+        })
+    }
+}
+
+/// One measurement of a search.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Stat {
+    /// A running total, such as the number of states expanded.
+    Count(u64),
+    /// The largest value reported, such as the size of a queue at its fullest.
+    Max(u64),
+    Histogram(Histogram),
+}
+
+/// Everything reported while collecting, in the order each name was first reported.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stats(Vec<(&'static str, Stat)>);
+
+impl Stats {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Stat)> {
+        self.0.iter().map(|(name, stat)| (*name, stat))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Stat> {
+        self.0.iter().find(|s| s.0 == name).map(|s| &s.1)
+    }
+
+    fn report(&mut self, name: &'static str, stat: Stat) {
+        let Some(i) = self.0.iter().position(|s| s.0 == name) else {
+            self.0.push((name, stat));
+            return;
+        };
+        match (&mut self.0[i].1, stat) {
+            (Stat::Count(a), Stat::Count(b)) => *a += b,
+            (Stat::Max(a), Stat::Max(b)) => *a = (*a).max(b),
+            (Stat::Histogram(a), Stat::Histogram(b)) => a.merge(&b),
+            (_, stat) => panic!("{} reported as both {:?} and {:?}", name, self.0[i].1, stat),
+        }
+    }
+}
+
+/// Lists the measurements one per line, each histogram followed by its buckets.
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.0.iter().map(|s| s.0.len()).max().unwrap_or(0);
+        for (name, stat) in &self.0 {
+            match stat {
+                Stat::Count(n) | Stat::Max(n) => writeln!(f, "{:width$}  {}", name, n)?,
+                Stat::Histogram(h) => {
+                    write!(f, "{:width$}  {} values", name, h.count())?;
+                    if let (Some(min), Some(mean), Some(max)) = (h.min(), h.mean(), h.max()) {
+                        write!(f, ", min {}, mean {:.1}, max {}", min, mean, max)?;
+                    }
+                    writeln!(f)?;
+                    for (lo, hi, n) in h.buckets() {
+                        let range = match lo == hi {
+                            true => lo.to_string(),
+                            false => format!("{}-{}", lo, hi),
+                        };
+                        writeln!(f, "{:width$}    {}: {}", "", range, n)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether the current thread is collecting, for reports that take work to put together.
+pub fn enabled() -> bool {
+    STATS.with_borrow(Option::is_some)
+}
+
+fn report(name: &'static str, stat: Stat) {
+    STATS.with_borrow_mut(|stats| {
+        if let Some(stats) = stats {
+            stats.report(name, stat);
+        }
+    });
+}
+
+/// Adds `n` to the count called `name`.
+pub fn count(name: &'static str, n: u64) {
+    report(name, Stat::Count(n));
+}
+
+/// Raises the high-water mark called `name` to `value`, if it's higher.
+pub fn max(name: &'static str, value: u64) {
+    report(name, Stat::Max(value));
+}
+
+/// Adds the values in `histogram` to the histogram called `name`.
+pub fn histogram(name: &'static str, histogram: &Histogram) {
+    if enabled() {
+        report(name, Stat::Histogram(histogram.clone()));
+    }
+}
+
+/// Runs `f`, returning what it reported along with its result. Collections can be nested; the
+/// inner one sees only its own reports.
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Stats) {
+    let outer = STATS.replace(Some(Stats::default()));
+    let result = f();
+    let stats = STATS.replace(outer).unwrap_or_default();
+    (result, stats)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{collect, count, enabled, histogram, max, Histogram, Stat};
+
+    #[test]
+    fn test_collect() {
+        count("expanded", 1);
+        assert!(!enabled());
+        let ((), stats) = collect(|| {
+            count("expanded", 2);
+            max("queue", 5);
+            let ((), inner) = collect(|| count("expanded", 10));
+            assert_eq!(Some(&Stat::Count(10)), inner.get("expanded"));
+            count("expanded", 3);
+            max("queue", 4);
+        });
+        assert!(!enabled());
+        let names: Vec<_> = stats.iter().map(|s| s.0).collect();
+        assert_eq!(vec!["expanded", "queue"], names);
+        assert_eq!(Some(&Stat::Count(5)), stats.get("expanded"));
+        assert_eq!(Some(&Stat::Max(5)), stats.get("queue"));
+        assert_eq!("expanded  5\nqueue     5\n", stats.to_string());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let ((), stats) = collect(|| {
+            let mut h = Histogram::new();
+            [0, 1, 2, 3, 6].into_iter().for_each(|v| h.add(v));
+            histogram("cut", &h);
+            histogram("cut", &h);
+        });
+        let Some(Stat::Histogram(h)) = stats.get("cut") else {
+            panic!("No histogram");
+        };
+        assert_eq!((10, Some(0), Some(6), Some(2.4)), (h.count(), h.min(), h.max(), h.mean()));
+        let buckets: Vec<_> = h.buckets().collect();
+        assert_eq!(vec![(0, 0, 2), (1, 1, 2), (2, 3, 4), (4, 7, 2)], buckets);
+        assert_eq!(None, Histogram::new().mean());
+    }
+}
@@ -0,0 +1,236 @@
+//! Runs every part of every selected solution in parallel, checking the answers as it goes.
+//!
+//! Each part runs in its own `aoc run` process, so one that takes too long can be killed, and
+//! one that panics or overflows its stack is reported without taking the others down with it.
+
+use crate::answers::{self, Answers};
+use crate::registry::Entry;
+use aoc_core::input::Source;
+use aoc_core::THREADS_VAR;
+use serde::Deserialize;
+use std::env;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Child, Command, ExitCode, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often to check whether a part has finished.
+const POLL: Duration = Duration::from_millis(5);
+
+/// The fields of `run --format json` output that the summary uses.
+#[derive(Deserialize)]
+struct Output {
+    answer: String,
+    time_ns: u64,
+    parse_ns: u64,
+    input: String,
+}
+
+/// How running one part went.
+enum Outcome {
+    /// The part finished, in this time (including parsing), with an answer to check.
+    Answer(String, Duration, Status),
+    /// The part was killed after running for the timeout.
+    Timeout,
+    /// The part couldn't be run, or exited unsuccessfully, for the given reason.
+    Error(String),
+    /// There's no input to run the part against.
+    NoInput,
+}
+
+/// How a part is labelled in the table, in the same order as `Status`, then timeouts and errors.
+const LABELS: [&str; 5] = ["pass", "FAIL", "-", "TIMEOUT", "ERROR"];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+/// Reads everything from `pipe` on another thread, so that the child writing to it never waits
+/// for the pipe to be emptied.
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_string(&mut text).ok();
+        }
+        text
+    })
+}
+
+/// Waits for `child` to exit, giving its status and everything it wrote to stdout and stderr,
+/// or kills it once it has been running for `timeout`.
+fn finish(mut child: Child, timeout: Duration) -> Result<(ExitStatus, String, String), Outcome> {
+    let start = Instant::now();
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(Outcome::Timeout);
+            }
+            Ok(None) => thread::sleep(POLL),
+            Err(e) => return Err(Outcome::Error(e.to_string())),
+        }
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    Ok((status, stdout, stderr))
+}
+
+/// Runs part `part` of `d` in a child process, letting it use up to `threads` threads, and
+/// killing it if it takes longer than `timeout`.
+fn run_part(d: &Entry, part: u8, threads: usize, timeout: Duration) -> Outcome {
+    if Source::Default.read(&d.dir()).is_err() {
+        return Outcome::NoInput;
+    }
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => return Outcome::Error(e.to_string()),
+    };
+    let child = match Command::new(exe)
+        .args(["run", &d.year.to_string(), &d.day.to_string()])
+        .args(["--part", &part.to_string(), "--format", "json"])
+        .env(THREADS_VAR, threads.to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => return Outcome::Error(e.to_string()),
+    };
+
+    let (status, stdout, stderr) = match finish(child, timeout) {
+        Ok(finished) => finished,
+        Err(outcome) => return outcome,
+    };
+    if !status.success() {
+        let last = stderr.lines().rfind(|l| !l.trim().is_empty()).unwrap_or("");
+        return Outcome::Error(format!("{} {}", status, last));
+    }
+    let output: Output = match serde_json::from_str(stdout.trim()) {
+        Ok(output) => output,
+        Err(e) => return Outcome::Error(format!("bad output: {}", e)),
+    };
+
+    // The answers to check against are the ones recorded for whichever input was used.
+    let name = Path::new(&output.input)
+        .file_stem()
+        .map_or(String::new(), |s| s.to_string_lossy().into_owned());
+    let expected = Answers::load(&d.dir())
+        .ok()
+        .and_then(|a| a.get(&name, part));
+    let status = match expected {
+        Some(want) if answers::matches(&output.answer, &want) => Status::Pass,
+        Some(_) => Status::Fail,
+        None => Status::Missing,
+    };
+    let time = Duration::from_nanos(output.time_ns + output.parse_ns);
+    Outcome::Answer(output.answer, time, status)
+}
+
+/// Shortens an answer to a single line for the table.
+fn summarise(answer: &str) -> String {
+    let mut lines = answer.trim_end().lines();
+    let first = lines.next().unwrap_or_default();
+    match lines.count() {
+        0 => first.to_string(),
+        n => format!("{} (+{} lines)", first, n),
+    }
+}
+
+/// Runs both parts of every day in `days` on up to `jobs` cores at once, then prints a table of
+/// their answers and times in day order, and whether each matches the recorded answer.
+pub fn all(days: &[&Entry], jobs: usize, timeout: Duration) -> ExitCode {
+    let tasks: Vec<(&Entry, u8)> = days.iter().flat_map(|&d| [(d, 1), (d, 2)]).collect();
+    let results: Vec<Mutex<Option<Outcome>>> = tasks.iter().map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+    // Share the CPUs between the parts running at once, rather than each assuming it has them all.
+    let cpus = thread::available_parallelism().map_or(1, |n| n.get());
+    let threads = (cpus / jobs.max(1)).max(1);
+
+    let start = Instant::now();
+    thread::scope(|s| {
+        for _ in 0..jobs.max(1) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&(d, part)) = tasks.get(i) else { break };
+                *results[i].lock().unwrap() = Some(run_part(d, part, threads, timeout));
+            });
+        }
+    });
+    let wall = start.elapsed();
+
+    let mut counts = [0; 5];
+    let mut cpu = Duration::ZERO;
+    println!("{:<9}{:<6}{:<8}{:>12}  answer", "day", "part", "status", "time");
+    for ((d, part), result) in tasks.iter().zip(results) {
+        let (kind, time, detail) = match result.into_inner().unwrap().unwrap() {
+            Outcome::Answer(answer, time, status) => {
+                cpu += time;
+                (status as usize, format!("{:.3?}", time), summarise(&answer))
+            }
+            Outcome::Timeout => (3, format!(">{:?}", timeout), String::new()),
+            Outcome::Error(e) => (4, String::new(), e),
+            Outcome::NoInput => (Status::Missing as usize, String::new(), "no input".to_string()),
+        };
+        counts[kind] += 1;
+        let label = LABELS[kind];
+        println!("{}/{:02}  {:<6}{:<8}{:>12}  {}", d.year, d.day, part, label, time, detail);
+    }
+
+    println!();
+    println!(
+        "{} passed, {} failed, {} unchecked, {} timed out, {} errors",
+        counts[0], counts[1], counts[2], counts[3], counts[4]
+    );
+    println!("{:.3?} wall time, {:.3?} total solution time", wall, cpu);
+
+    if counts[1] + counts[3] + counts[4] == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{finish, summarise, Outcome};
+    use std::process::{Command, Stdio};
+    use std::time::Duration;
+
+    #[test]
+    fn test_summarise() {
+        assert_eq!("102", summarise("102"));
+        assert_eq!("###..## (+2 lines)", summarise("###..##\n#..#.#.\n#..#.##\n"));
+    }
+
+    #[test]
+    fn test_finish() {
+        // Far more output than a pipe holds, which has to be read while the child runs.
+        let child = Command::new("sh")
+            .args(["-c", "yes | head -c 1000000; echo done >&2"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let Ok((status, stdout, stderr)) = finish(child, Duration::from_secs(10)) else {
+            panic!("Child didn't finish");
+        };
+        assert!(status.success());
+        assert_eq!(1000000, stdout.len());
+        assert_eq!("done\n", stderr);
+
+        let child = Command::new("sleep").arg("10").spawn().unwrap();
+        assert!(matches!(finish(child, Duration::from_millis(50)), Err(Outcome::Timeout)));
+    }
+}
@@ -0,0 +1,120 @@
+//! Counts what the solutions allocate, for `run --memory`.
+//!
+//! The runner's global allocator passes everything through to the system allocator, and only
+//! keeps count once tracking is turned on, so the other commands pay for no more than a check of
+//! a flag. The counts cover every thread.
+
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering::Relaxed};
+
+static TRACKING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+/// The bytes allocated less those freed since tracking started, which can go below zero as
+/// memory allocated beforehand is freed.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+struct Counting;
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    resized(size as isize);
+}
+
+fn resized(by: isize) {
+    let now = CURRENT.fetch_add(by, Relaxed) + by;
+    PEAK.fetch_max(now, Relaxed);
+}
+
+// SAFETY: every call is passed straight on to the system allocator.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && TRACKING.load(Relaxed) {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && TRACKING.load(Relaxed) {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if TRACKING.load(Relaxed) {
+            resized(-(layout.size() as isize));
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() && TRACKING.load(Relaxed) {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            BYTES.fetch_add(new_size, Relaxed);
+            resized(new_size as isize - layout.size() as isize);
+        }
+        new
+    }
+}
+
+/// Starts counting allocations.
+pub fn track() {
+    TRACKING.store(true, Relaxed);
+}
+
+/// What was allocated while running something.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct Usage {
+    /// How many times memory was allocated or resized.
+    pub allocations: u64,
+    /// The bytes asked for, in all.
+    pub bytes: u64,
+    /// The most bytes held at once, over what was held at the start.
+    pub peak_bytes: u64,
+}
+
+/// Runs `f`, returning what it allocated along with its result. All zero unless tracking.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let (allocations, bytes) = (ALLOCATIONS.load(Relaxed), BYTES.load(Relaxed));
+    let base = CURRENT.load(Relaxed);
+    PEAK.store(base, Relaxed);
+    let result = f();
+    let usage = Usage {
+        allocations: (ALLOCATIONS.load(Relaxed) - allocations) as u64,
+        bytes: (BYTES.load(Relaxed) - bytes) as u64,
+        peak_bytes: (PEAK.load(Relaxed) - base).max(0) as u64,
+    };
+    (result, usage)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{measure, track};
+    use std::hint::black_box;
+
+    #[test]
+    fn test_measure() {
+        track();
+        // Other tests allocate at the same time, so these are only lower bounds.
+        let (_, usage) = measure(|| {
+            let mut v = black_box(Vec::<u8>::with_capacity(1000));
+            v.extend([1; 3000]);
+            drop(black_box(v));
+            black_box(vec![0u64; 100])
+        });
+        assert!(usage.allocations >= 3);
+        assert!(usage.bytes >= 1000 + 3000 + 800);
+        assert!(usage.peak_bytes >= 3000);
+    }
+}
@@ -0,0 +1,417 @@
+//! Plays a solution's frames as an animation in the terminal, for the days that can draw
+//! themselves.
+//!
+//! Each cell is half a character tall, drawn with `▀` in 24-bit colour: its foreground is the
+//! upper cell and its background the lower. Frames bigger than the terminal are shown through a
+//! viewport that can be scrolled.
+
+use crate::registry::Entry;
+use crate::render::Visualiser;
+use aoc_core::input::Source;
+use aoc_core::render::{Frame, Rgb};
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::mem::MaybeUninit;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+/// How to play the frames.
+pub struct Options {
+    /// Show only every this many frames, and the last.
+    pub every: usize,
+    /// How long to show each frame to start with, in hundredths of a second.
+    pub delay: u16,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Key {
+    Pause,
+    Step,
+    Faster,
+    Slower,
+    Up,
+    Down,
+    Left,
+    Right,
+    Quit,
+}
+
+/// The keys pressed in some input read from the terminal, ignoring any that aren't used.
+fn keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let key = match &bytes[i..] {
+            [0x1b, b'[', arrow @ b'A'..=b'D', ..] => {
+                i += 2;
+                match arrow {
+                    b'A' => Some(Key::Up),
+                    b'B' => Some(Key::Down),
+                    b'C' => Some(Key::Right),
+                    _ => Some(Key::Left),
+                }
+            }
+            [0x1b] => Some(Key::Quit),
+            // Any other escape sequence.
+            [0x1b, ..] => {
+                i = bytes.len();
+                None
+            }
+            [b'q' | 0x03, ..] => Some(Key::Quit),
+            [b' ', ..] => Some(Key::Pause),
+            [b'n' | b'.', ..] => Some(Key::Step),
+            [b'+' | b'=', ..] => Some(Key::Faster),
+            [b'-' | b'_', ..] => Some(Key::Slower),
+            [b'k', ..] => Some(Key::Up),
+            [b'j', ..] => Some(Key::Down),
+            [b'h', ..] => Some(Key::Left),
+            [b'l', ..] => Some(Key::Right),
+            _ => None,
+        };
+        keys.extend(key);
+        i += 1;
+    }
+    keys
+}
+
+/// The terminal in raw mode, showing the alternate screen. Dropping it puts everything back.
+struct Terminal {
+    saved: libc::termios,
+    out: io::Stdout,
+}
+
+impl Terminal {
+    fn open() -> io::Result<Terminal> {
+        // SAFETY: the termios structs are only read once tcgetattr has filled them in.
+        let saved = unsafe {
+            if libc::isatty(libc::STDIN_FILENO) == 0 || libc::isatty(libc::STDOUT_FILENO) == 0 {
+                return Err(io::Error::other("--animate needs a terminal"));
+            }
+            let mut saved = MaybeUninit::uninit();
+            if libc::tcgetattr(libc::STDIN_FILENO, saved.as_mut_ptr()) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let saved = saved.assume_init();
+            let mut raw = saved;
+            libc::cfmakeraw(&mut raw);
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            saved
+        };
+        let mut terminal = Terminal {
+            saved,
+            out: io::stdout(),
+        };
+        terminal.write("\x1b[?1049h\x1b[?25l\x1b[2J")?;
+        Ok(terminal)
+    }
+
+    /// The number of columns and rows, or the usual 80 by 24 if the terminal won't say.
+    fn size(&self) -> (usize, usize) {
+        // SAFETY: TIOCGWINSZ fills in a winsize, which is only read if it succeeds.
+        unsafe {
+            let mut size = MaybeUninit::<libc::winsize>::uninit();
+            if libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, size.as_mut_ptr()) == 0 {
+                let size = size.assume_init();
+                if size.ws_col > 0 && size.ws_row > 0 {
+                    return (size.ws_col as usize, size.ws_row as usize);
+                }
+            }
+        }
+        (80, 24)
+    }
+
+    /// The keys pressed within `timeout`, or forever if there isn't one. Returns no keys if the
+    /// time runs out.
+    fn keys(&self, timeout: Option<Duration>) -> io::Result<Vec<Key>> {
+        let mut fd = libc::pollfd {
+            fd: libc::STDIN_FILENO,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = timeout.map_or(-1, |t| t.as_millis().min(i32::MAX as u128) as i32);
+        // SAFETY: poll is given the one pollfd, and read at most the length of the buffer.
+        unsafe {
+            match libc::poll(&mut fd, 1, timeout) {
+                0 => return Ok(vec![]),
+                n if n < 0 => return Err(io::Error::last_os_error()),
+                _ => {}
+            }
+            let mut buf = [0u8; 64];
+            let n = libc::read(libc::STDIN_FILENO, buf.as_mut_ptr().cast(), buf.len());
+            if n < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            // Stdin has closed, so nothing more can be pressed.
+            if n == 0 {
+                return Ok(vec![Key::Quit]);
+            }
+            Ok(keys(&buf[..n as usize]))
+        }
+    }
+
+    fn write(&mut self, text: &str) -> io::Result<()> {
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = self.write("\x1b[0m\x1b[?25h\x1b[?1049l");
+        // SAFETY: restores the settings read by tcgetattr.
+        unsafe {
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.saved);
+        }
+    }
+}
+
+/// Draws the part of `frame` starting at cell (`x`, `y`) that fits in `columns` by `rows`
+/// characters, from the top left of the screen.
+fn draw(frame: &Frame, x: usize, y: usize, columns: usize, rows: usize) -> String {
+    let mut out = String::from("\x1b[H");
+    let width = frame.width().saturating_sub(x).min(columns);
+    for row in 0..rows {
+        let (upper, lower) = (y + 2 * row, y + 2 * row + 1);
+        if upper >= frame.height() {
+            out.push_str("\x1b[0m\x1b[J");
+            break;
+        }
+        let mut colours: Option<(Rgb, Option<Rgb>)> = None;
+        for col in x..x + width {
+            let below = (lower < frame.height()).then(|| frame.rgb(col, lower));
+            let cell = (frame.rgb(col, upper), below);
+            if colours != Some(cell) {
+                let ([r, g, b], bg) = cell;
+                let _ = write!(out, "\x1b[38;2;{};{};{}m", r, g, b);
+                let _ = match bg {
+                    Some([r, g, b]) => write!(out, "\x1b[48;2;{};{};{}m", r, g, b),
+                    None => write!(out, "\x1b[49m"),
+                };
+                colours = Some(cell);
+            }
+            out.push('▀');
+        }
+        out.push_str("\x1b[0m\x1b[K\r\n");
+    }
+    out
+}
+
+/// Shows frames one after another, reading keys between them.
+struct Player<'a> {
+    terminal: Terminal,
+    name: String,
+    options: &'a Options,
+    /// How long to show each frame.
+    time: Duration,
+    paused: bool,
+    quit: bool,
+    /// The top left cell of the viewport, or `None` to centre the first frame.
+    view: Option<(usize, usize)>,
+    count: usize,
+    shown: usize,
+    /// The last frame, and whether it was skipped.
+    last: Option<(Frame, bool)>,
+    error: Option<io::Error>,
+}
+
+impl Player<'_> {
+    fn frame(&mut self, frame: &Frame) {
+        self.count += 1;
+        if self.quit {
+            return;
+        }
+        let skip = !(self.count - 1).is_multiple_of(self.options.every);
+        self.last = Some((frame.clone(), skip));
+        if skip {
+            return;
+        }
+        self.shown += 1;
+        if let Err(e) = self.play(frame, false) {
+            self.error = Some(e);
+            self.quit = true;
+        }
+    }
+
+    /// Shows the last frame until the player quits.
+    fn finish(&mut self) {
+        if let Some((frame, skipped)) = self.last.take().filter(|_| !self.quit) {
+            self.shown += skipped as usize;
+            if let Err(e) = self.play(&frame, true) {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    /// The size of the screen, less the status line, and the cells it fits.
+    fn viewport(&self) -> (usize, usize, usize, usize) {
+        let (columns, rows) = self.terminal.size();
+        let rows = rows.saturating_sub(1).max(1);
+        (columns, rows, columns, rows * 2)
+    }
+
+    fn redraw(&mut self, frame: &Frame, finished: bool) -> io::Result<()> {
+        let (columns, rows, width, height) = self.viewport();
+        let max_x = frame.width().saturating_sub(width);
+        let max_y = frame.height().saturating_sub(height);
+        let (x, y) = self.view.unwrap_or((max_x / 2, max_y / 2));
+        let (x, y) = (x.min(max_x), y.min(max_y));
+        self.view = Some((x, y));
+
+        let mut out = draw(frame, x, y, columns, rows);
+        let state = if finished {
+            "finished".to_string()
+        } else if self.paused {
+            "paused".to_string()
+        } else {
+            format!("{:.1} fps", 1.0 / self.time.as_secs_f64())
+        };
+        let mut status = format!(
+            "{}  frame {}  {}  space: pause  n: step  +/-: speed  arrows/hjkl: scroll  q: quit",
+            self.name, self.count, state
+        );
+        status.truncate(status.char_indices().nth(columns).map_or(status.len(), |(i, _)| i));
+        let _ = write!(out, "\x1b[{};1H\x1b[7m{}\x1b[0m\x1b[K", rows + 1, status);
+        self.terminal.write(&out)
+    }
+
+    /// Shows `frame` for the frame time, or while paused, acting on keys as they're pressed.
+    /// Once `finished`, it's shown until the player quits.
+    fn play(&mut self, frame: &Frame, finished: bool) -> io::Result<()> {
+        self.redraw(frame, finished)?;
+        let mut deadline = Instant::now() + self.time;
+        loop {
+            let timeout = match self.paused || finished {
+                true => None,
+                false => Some(deadline.saturating_duration_since(Instant::now())),
+            };
+            let keys = self.terminal.keys(timeout)?;
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let (_, _, width, height) = self.viewport();
+            let (x, y) = self.view.unwrap_or_default();
+            let (dx, dy) = ((width / 4).max(1), (height / 4).max(1));
+            for key in keys {
+                match key {
+                    Key::Quit => {
+                        self.quit = true;
+                        return Ok(());
+                    }
+                    Key::Step if self.paused && !finished => return Ok(()),
+                    Key::Step => {}
+                    Key::Pause => {
+                        self.paused = !self.paused;
+                        deadline = Instant::now() + self.time;
+                    }
+                    Key::Faster => self.time = (self.time / 2).max(Duration::from_millis(1)),
+                    Key::Slower => self.time = (self.time * 2).min(Duration::from_secs(10)),
+                    Key::Up => self.view = Some((x, y.saturating_sub(dy))),
+                    Key::Down => self.view = Some((x, y + dy)),
+                    Key::Left => self.view = Some((x.saturating_sub(dx), y)),
+                    Key::Right => self.view = Some((x + dx, y)),
+                }
+            }
+            self.redraw(frame, finished)?;
+        }
+    }
+}
+
+/// Runs `visualise` on the day's input, playing its frames in the terminal.
+pub fn animate(
+    d: &Entry,
+    visualise: Visualiser,
+    input: Option<Source>,
+    options: &Options,
+) -> ExitCode {
+    let source = input.unwrap_or(Source::Default);
+    let data = match source.read(&d.dir()) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("{}/{:02}: {}", d.year, d.day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let terminal = match Terminal::open() {
+        Ok(terminal) => terminal,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut player = Player {
+        terminal,
+        name: format!("{}/{:02}", d.year, d.day),
+        options,
+        time: Duration::from_millis(options.delay as u64 * 10).max(Duration::from_millis(1)),
+        paused: false,
+        quit: false,
+        view: None,
+        count: 0,
+        shown: 0,
+        last: None,
+        error: None,
+    };
+    let result = visualise(&data.text, &mut |frame| player.frame(frame));
+    if result.is_ok() {
+        player.finish();
+    }
+    let Player {
+        terminal,
+        count,
+        shown,
+        error,
+        ..
+    } = player;
+    drop(terminal);
+
+    if data.is_example() && matches!(source, Source::Default) {
+        eprintln!("{}/{:02}: No data/input.txt, used data/example.txt", d.year, d.day);
+    }
+    if let Err(e) = result {
+        eprintln!("{}", e.for_day(d.year, d.day));
+        return ExitCode::FAILURE;
+    }
+    if let Some(e) = error {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    println!("Showed {} of {} frames", shown, count);
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::{draw, keys, Key};
+    use aoc_core::render::Frame;
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(vec![Key::Pause, Key::Step, Key::Faster, Key::Quit], keys(b" n+q"));
+        assert_eq!(vec![Key::Up, Key::Left, Key::Down], keys(b"\x1b[A\x1b[Dj"));
+        assert_eq!(vec![Key::Quit], keys(b"\x1b"));
+        assert_eq!(vec![Key::Slower], keys(b"x-\x1b[5~"));
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut frame = Frame::new(3, 3, &[[0, 0, 0], [255, 0, 0]]);
+        frame.set(1, 0, 1);
+        frame.set(1, 2, 1);
+        let out = draw(&frame, 1, 0, 2, 5);
+        let rows: Vec<_> = out.strip_prefix("\x1b[H").unwrap().split("\r\n").collect();
+        let (red, black) = ("\x1b[38;2;255;0;0m", "\x1b[38;2;0;0;0m");
+        let under = "\x1b[48;2;0;0;0m";
+        // Colours are only given when they change, and the last row has no lower half.
+        assert_eq!(
+            vec![
+                format!("{}{}▀{}{}▀\x1b[0m\x1b[K", red, under, black, under),
+                format!("{}\x1b[49m▀{}\x1b[49m▀\x1b[0m\x1b[K", red, black),
+                "\x1b[0m\x1b[J".to_string(),
+            ],
+            rows
+        );
+    }
+}
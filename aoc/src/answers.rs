@@ -0,0 +1,80 @@
+//! The answers recorded in each day's `data/answers.toml`.
+//!
+//! The file has a table for each input file in `data/`, named after the file without its `.txt`
+//! extension, holding the expected answer to either or both parts:
+//!
+//! ```toml
+//! [input]
+//! part1 = 74711
+//! part2 = 209481
+//!
+//! [example]
+//! part1 = 24000
+//! ```
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// The file, relative to a day's directory, holding its recorded answers.
+pub const ANSWERS_FILE: &str = "data/answers.toml";
+
+/// A day's recorded answers.
+#[derive(Default)]
+pub struct Answers(Table);
+
+impl Answers {
+    /// Loads the answers recorded for the day whose crate lives in `day_dir`. A day with no
+    /// answers file has no recorded answers.
+    pub fn load(day_dir: &Path) -> Result<Answers, String> {
+        let path = day_dir.join(ANSWERS_FILE);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        text.parse()
+            .map(Answers)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// The answer to `part` for the named input (e.g. `input` or `example2`), if recorded.
+    pub fn get(&self, input: &str, part: u8) -> Option<String> {
+        match self.0.get(input)?.get(format!("part{}", part))? {
+            Value::String(s) => Some(s.clone()),
+            v => Some(v.to_string()),
+        }
+    }
+}
+
+/// Whether an answer matches the recorded one. Trailing whitespace is ignored, so multi-line
+/// answers can be recorded without worrying about the final newline.
+pub fn matches(answer: &str, expected: &str) -> bool {
+    answer.trim_end() == expected.trim_end()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{matches, Answers};
+
+    #[test]
+    fn test_get() {
+        let answers = Answers(
+            "[input]\npart1 = 123\npart2 = \"ABC\"\n[example2]\npart2 = \"\"\"\n#.\n.#\n\"\"\""
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(Some("123".to_string()), answers.get("input", 1));
+        assert_eq!(Some("ABC".to_string()), answers.get("input", 2));
+        assert_eq!(None, answers.get("example2", 1));
+        assert_eq!(Some("#.\n.#\n".to_string()), answers.get("example2", 2));
+        assert_eq!(None, answers.get("example", 1));
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("#.\n.#\n", "#.\n.#"));
+        assert!(!matches("123", "124"));
+    }
+}
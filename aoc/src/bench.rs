@@ -0,0 +1,247 @@
+//! Times each phase of the solutions, keeping a history of results to spot regressions.
+
+use crate::registry::Entry;
+use aoc_core::input::Source;
+use aoc_core::parse::ParseError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Slowdowns smaller than this are put down to noise, however large they are in relative terms.
+const NOISE: Duration = Duration::from_micros(100);
+
+/// The names of the timed phases, in the order they run.
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Timings for a single phase, in nanoseconds.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct Stats {
+    median_ns: u64,
+    min_ns: u64,
+    max_ns: u64,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        let ns = |d: Duration| d.as_nanos() as u64;
+        Stats {
+            median_ns: ns(samples[samples.len() / 2]),
+            min_ns: ns(samples[0]),
+            max_ns: ns(samples[samples.len() - 1]),
+        }
+    }
+}
+
+/// The timings of each phase of one day's solution.
+#[derive(Serialize, Deserialize)]
+struct DayStats {
+    year: u16,
+    day: u8,
+    parse: Stats,
+    part1: Stats,
+    part2: Stats,
+}
+
+impl DayStats {
+    fn phases(&self) -> [Stats; 3] {
+        [self.parse, self.part1, self.part2]
+    }
+}
+
+/// A single benchmark run, as stored in the history file.
+#[derive(Serialize, Deserialize)]
+struct Record {
+    /// Seconds since the Unix epoch.
+    timestamp: u64,
+    iterations: u32,
+    days: Vec<DayStats>,
+}
+
+fn load_history(path: &Path) -> Result<Vec<Record>, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+fn save_history(path: &Path, history: &[Record]) -> Result<(), String> {
+    let text = serde_json::to_string_pretty(history).unwrap();
+    fs::write(path, text + "\n").map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// The most recently recorded timings for the given day.
+fn previous<'a>(history: &'a [Record], d: &Entry) -> Option<&'a DayStats> {
+    history
+        .iter()
+        .rev()
+        .flat_map(|r| r.days.iter())
+        .find(|s| s.year == d.year && s.day == d.day)
+}
+
+/// How many percent slower the median of `new` is than that of `old`, if that's more than
+/// `threshold` percent and more than can be put down to noise.
+fn regression(old: &Stats, new: &Stats, threshold: f64) -> Option<f64> {
+    let slowdown = new.median_ns.saturating_sub(old.median_ns);
+    let percent = 100.0 * slowdown as f64 / old.median_ns.max(1) as f64;
+    (percent > threshold && slowdown > NOISE.as_nanos() as u64).then_some(percent)
+}
+
+/// Runs every phase of `d` against its input `iterations` times.
+fn measure(d: &Entry, input: &str, iterations: u32) -> Result<[Stats; 3], ParseError> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..iterations {
+        let run = d.run(&[1, 2], input)?;
+        samples[0].push(run.parse);
+        for (i, (_, t)) in run.parts.into_iter().enumerate() {
+            samples[i + 1].push(t);
+        }
+    }
+    Ok(samples.map(Stats::new))
+}
+
+/// Times `iterations` runs of each day, printing the median, minimum and maximum of each phase
+/// and appending them to the history file. Any phase whose median is more than `threshold`
+/// percent slower than in the last recorded run is flagged, and makes the command fail.
+pub fn bench(days: &[&Entry], iterations: u32, threshold: f64, history_path: &Path) -> ExitCode {
+    let mut history = match load_history(history_path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut results = vec![];
+    println!("{:<9}{:<7}{:>12}{:>12}{:>12}", "day", "phase", "median", "min", "max");
+    for d in days {
+        let input = match Source::Default.read(&d.dir()) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}/{:02}: {}", d.year, d.day, e);
+                continue;
+            }
+        };
+
+        let phases = match measure(d, &input.text, iterations) {
+            Ok(phases) => phases,
+            Err(e) => {
+                eprintln!("{}", e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let last = previous(&history, d);
+        for (i, stats) in phases.iter().enumerate() {
+            let t = |ns: u64| format!("{:.3?}", Duration::from_nanos(ns));
+            let mut line = format!(
+                "{}/{:02}  {:<7}{:>12}{:>12}{:>12}",
+                d.year,
+                d.day,
+                PHASES[i],
+                t(stats.median_ns),
+                t(stats.min_ns),
+                t(stats.max_ns)
+            );
+            if let Some(old) = last.map(|l| l.phases()[i]) {
+                if let Some(percent) = regression(&old, stats, threshold) {
+                    line += &format!("  REGRESSED {:+.0}% (was {})", percent, t(old.median_ns));
+                    status = ExitCode::FAILURE;
+                }
+            }
+            println!("{}", line);
+        }
+        let [parse, part1, part2] = phases;
+        results.push(DayStats {
+            year: d.year,
+            day: d.day,
+            parse,
+            part1,
+            part2,
+        });
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    history.push(Record {
+        timestamp,
+        iterations,
+        days: results,
+    });
+    if let Err(e) = save_history(history_path, &history) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    status
+}
+
+#[cfg(test)]
+mod test {
+    use super::{load_history, previous, regression, DayStats, Record, Stats};
+    use crate::registry::DAYS;
+    use std::env;
+    use std::time::Duration;
+
+    fn stats(median_ms: u64) -> Stats {
+        let ns = median_ms * 1_000_000;
+        Stats {
+            median_ns: ns,
+            min_ns: ns,
+            max_ns: ns,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = |n: &[u64]| n.iter().map(|&n| Duration::from_millis(n)).collect();
+        let stats = Stats::new(ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(
+            (3_000_000, 1_000_000, 5_000_000),
+            (stats.median_ns, stats.min_ns, stats.max_ns)
+        );
+        // With an even number of samples, the median is the upper of the middle two.
+        assert_eq!(3_000_000, Stats::new(ms(&[4, 1, 3, 2])).median_ns);
+        assert_eq!(7_000_000, Stats::new(ms(&[7])).min_ns);
+    }
+
+    #[test]
+    fn test_regression() {
+        assert_eq!(Some(10.0), regression(&stats(10), &stats(11), 5.0));
+        assert_eq!(None, regression(&stats(10), &stats(11), 10.0));
+        assert_eq!(None, regression(&stats(10), &stats(9), 0.0));
+        // Much slower in relative terms, but by less than the noise.
+        let micros = |n: u64| Stats::new(vec![Duration::from_micros(n)]);
+        assert_eq!(None, regression(&micros(1), &micros(50), 5.0));
+        assert!(regression(&micros(1), &micros(500), 5.0).is_some());
+    }
+
+    #[test]
+    fn test_previous() {
+        let d = &DAYS[0];
+        let day = |median_ms| DayStats {
+            year: d.year,
+            day: d.day,
+            parse: stats(median_ms),
+            part1: stats(median_ms),
+            part2: stats(median_ms),
+        };
+        let record = |days| Record {
+            timestamp: 0,
+            iterations: 1,
+            days,
+        };
+        let history = vec![record(vec![day(1)]), record(vec![day(2)]), record(vec![])];
+        assert_eq!(Some(2_000_000), previous(&history, d).map(|s| s.parse.median_ns));
+        assert!(previous(&[], d).is_none());
+
+        let missing = env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        assert!(load_history(&missing).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,465 @@
+//! An interactive step debugger for the days that run small machines: 2022/10's CPU, 2022/11's
+//! monkeys and 2023/20's pulses.
+//!
+//! Commands are read a line at a time, as in gdb: step, next, continue, break, delete, info,
+//! print, help and quit, each of which can be shortened to its first letter. An empty line repeats
+//! the last command.
+
+use crate::registry::Entry;
+use aoc_core::debug::{State, Value};
+use aoc_core::input::Source;
+use aoc_core::parse::ParseError;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+/// Runs a day's machine on the given input, for the given part, handing `stepped` the state
+/// after each step. `stepped` returns false to stop the machine.
+pub type Debugger = fn(&str, u8, &mut dyn FnMut(&dyn State) -> bool) -> Result<(), ParseError>;
+
+/// Each day that can be debugged, with its year and day.
+pub const DEBUGGERS: &[(u16, u8, Debugger)] = &[
+    (2022, 10, aoc_2022_10::debug),
+    (2022, 11, aoc_2022_11::debug),
+    (2023, 20, aoc_2023_20::debug),
+];
+
+/// The debugger for a day.
+pub fn find(year: u16, day: u8) -> Option<Debugger> {
+    DEBUGGERS
+        .iter()
+        .find(|v| v.0 == year && v.1 == day)
+        .map(|v| v.2)
+}
+
+const HELP: &str = "\
+step [n]        (s) run n steps, 1 by default
+next            (n) run until the next {unit}
+continue        (c) run until a breakpoint
+break <cond>    (b) stop when the condition holds, e.g. `break x == 21 && cycle > 100`
+delete <n>      (d) remove breakpoint n
+info            (i) list the breakpoints
+print [field]   (p) show the whole state, or one field
+quit            (q) stop debugging
+Fields: {fields}";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A field compared with a value, such as `x == 21`.
+#[derive(Debug, PartialEq)]
+struct Comparison {
+    field: String,
+    op: Op,
+    value: Value,
+}
+
+impl Comparison {
+    /// Whether the comparison holds. Numbers are compared with numbers and text with text; a
+    /// number never matches text.
+    fn holds(&self, fields: &[(&str, Value)]) -> bool {
+        let Some((_, value)) = fields.iter().find(|f| f.0 == self.field) else {
+            return false;
+        };
+        let ordering = match (value, &self.value) {
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Text(a), Value::Text(b)) => a.cmp(b),
+            _ => return false,
+        };
+        match self.op {
+            Op::Eq => ordering.is_eq(),
+            Op::Ne => ordering.is_ne(),
+            Op::Lt => ordering.is_lt(),
+            Op::Le => ordering.is_le(),
+            Op::Gt => ordering.is_gt(),
+            Op::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+/// Comparisons that must all hold, joined with `&&`.
+#[derive(Debug, PartialEq)]
+struct Condition(Vec<Comparison>);
+
+impl Condition {
+    /// Parses a condition, checking that it only tests the given fields.
+    fn parse(text: &str, fields: &[(&str, Value)]) -> Result<Condition, String> {
+        let ops = [
+            ("==", Op::Eq),
+            ("!=", Op::Ne),
+            ("<=", Op::Le),
+            (">=", Op::Ge),
+            ("<", Op::Lt),
+            (">", Op::Gt),
+        ];
+        let mut comparisons = vec![];
+        for part in text.split("&&") {
+            let part = part.trim();
+            let Some((at, (symbol, op))) = ops
+                .iter()
+                .filter_map(|&(symbol, op)| part.find(symbol).map(|at| (at, (symbol, op))))
+                .min_by_key(|m| m.0)
+            else {
+                return Err(format!("No comparison in `{}`", part));
+            };
+            let field = part[..at].trim();
+            if !fields.iter().any(|f| f.0 == field) {
+                let names: Vec<_> = fields.iter().map(|f| f.0).collect();
+                return Err(format!("No field `{}` (try {})", field, names.join(", ")));
+            }
+            let value = part[at + symbol.len()..].trim().trim_matches('"');
+            let value = match value.parse() {
+                Ok(n) => Value::Int(n),
+                Err(_) => Value::Text(value.to_string()),
+            };
+            comparisons.push(Comparison {
+                field: field.to_string(),
+                op,
+                value,
+            });
+        }
+        Ok(Condition(comparisons))
+    }
+
+    fn holds(&self, fields: &[(&str, Value)]) -> bool {
+        self.0.iter().all(|c| c.holds(fields))
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, c) in self.0.iter().enumerate() {
+            let op = match c.op {
+                Op::Eq => "==",
+                Op::Ne => "!=",
+                Op::Lt => "<",
+                Op::Le => "<=",
+                Op::Gt => ">",
+                Op::Ge => ">=",
+            };
+            let and = if i > 0 { " && " } else { "" };
+            write!(f, "{}{} {} {}", and, c.field, op, c.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// When to stop next, besides at a breakpoint.
+enum Mode {
+    /// After this many more steps.
+    Step(u64),
+    /// Once the unit field no longer has this value.
+    Next(Value),
+    Continue,
+}
+
+/// A debugging session, reading commands from `input` and writing to `output`.
+struct Session<R, W> {
+    input: R,
+    output: W,
+    /// Breakpoints by number.
+    breakpoints: Vec<(usize, Condition)>,
+    /// Breakpoints given up front, waiting for the first state to check them against.
+    pending: Vec<String>,
+    numbered: usize,
+    mode: Mode,
+    last: String,
+    steps: u64,
+    quit: bool,
+}
+
+impl<R: BufRead, W: Write> Session<R, W> {
+    fn new(input: R, output: W, breakpoints: &[String]) -> Self {
+        Session {
+            input,
+            output,
+            breakpoints: vec![],
+            pending: breakpoints.to_vec(),
+            numbered: 0,
+            mode: Mode::Step(1),
+            last: String::new(),
+            steps: 0,
+            quit: false,
+        }
+    }
+
+    /// Decides whether to stop after a step, and if so takes commands until told to go on.
+    /// Returns false to stop the machine.
+    fn stepped(&mut self, state: &dyn State) -> io::Result<bool> {
+        self.steps += 1;
+        let fields = state.fields();
+        for text in std::mem::take(&mut self.pending) {
+            self.add_breakpoint(&text, &fields)?;
+        }
+        let stop = match &mut self.mode {
+            Mode::Step(n) => {
+                *n -= 1;
+                *n == 0
+            }
+            Mode::Next(value) => fields.iter().any(|f| f.0 == state.unit() && f.1 != *value),
+            Mode::Continue => false,
+        };
+        let hit = self.breakpoints.iter().find(|b| b.1.holds(&fields));
+        if let Some((n, condition)) = hit {
+            writeln!(self.output, "Breakpoint {}: {}", n, condition)?;
+        } else if !stop {
+            return Ok(true);
+        }
+        writeln!(self.output, "[{}] {}", self.steps, state.describe())?;
+        self.prompt(state, &fields)
+    }
+
+    fn add_breakpoint(&mut self, text: &str, fields: &[(&str, Value)]) -> io::Result<()> {
+        match Condition::parse(text, fields) {
+            Ok(condition) => {
+                self.numbered += 1;
+                writeln!(self.output, "Breakpoint {}: {}", self.numbered, condition)?;
+                self.breakpoints.push((self.numbered, condition));
+            }
+            Err(e) => writeln!(self.output, "{}", e)?,
+        }
+        Ok(())
+    }
+
+    fn prompt(&mut self, state: &dyn State, fields: &[(&str, Value)]) -> io::Result<bool> {
+        loop {
+            write!(self.output, "(debug) ")?;
+            self.output.flush()?;
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                writeln!(self.output)?;
+                self.quit = true;
+                return Ok(false);
+            }
+            let line = match line.trim() {
+                "" => self.last.clone(),
+                line => line.to_string(),
+            };
+            self.last = line.clone();
+            let (command, arg) = line.split_once(' ').unwrap_or((&line, ""));
+            let arg = arg.trim();
+            match command {
+                "s" | "step" => match arg {
+                    "" => self.mode = Mode::Step(1),
+                    n => match n.parse() {
+                        Ok(n @ 1..) => self.mode = Mode::Step(n),
+                        _ => {
+                            writeln!(self.output, "Expected a number of steps, not `{}`", n)?;
+                            continue;
+                        }
+                    },
+                },
+                "n" | "next" => {
+                    let unit = fields.iter().find(|f| f.0 == state.unit());
+                    self.mode = Mode::Next(unit.map_or(Value::Int(0), |f| f.1.clone()));
+                }
+                "c" | "continue" => self.mode = Mode::Continue,
+                "b" | "break" => {
+                    self.add_breakpoint(arg, fields)?;
+                    continue;
+                }
+                "d" | "delete" => {
+                    match self.breakpoints.iter().position(|b| b.0.to_string() == arg) {
+                        Some(i) => {
+                            self.breakpoints.remove(i);
+                        }
+                        None => writeln!(self.output, "No breakpoint `{}`", arg)?,
+                    }
+                    continue;
+                }
+                "i" | "info" => {
+                    if self.breakpoints.is_empty() {
+                        writeln!(self.output, "No breakpoints")?;
+                    }
+                    for (n, condition) in &self.breakpoints {
+                        writeln!(self.output, "Breakpoint {}: {}", n, condition)?;
+                    }
+                    continue;
+                }
+                "p" | "print" => {
+                    match (arg, fields.iter().find(|f| f.0 == arg)) {
+                        ("", _) => writeln!(self.output, "{}", state.inspect())?,
+                        (_, Some((name, value))) => writeln!(self.output, "{} = {}", name, value)?,
+                        (_, None) => writeln!(self.output, "No field `{}`", arg)?,
+                    }
+                    continue;
+                }
+                "q" | "quit" => {
+                    self.quit = true;
+                    return Ok(false);
+                }
+                "h" | "help" => {
+                    let names: Vec<_> = fields.iter().map(|f| f.0).collect();
+                    let help = HELP
+                        .replace("{unit}", state.unit())
+                        .replace("{fields}", &names.join(", "));
+                    writeln!(self.output, "{}", help)?;
+                    continue;
+                }
+                _ => {
+                    writeln!(self.output, "Unknown command `{}` (try help)", command)?;
+                    continue;
+                }
+            }
+            return Ok(true);
+        }
+    }
+}
+
+/// Steps through `debug` on the day's input, reading commands from stdin. `breakpoints` are
+/// set before the first step.
+pub fn debug(
+    d: &Entry,
+    debug: Debugger,
+    input: Option<Source>,
+    part: u8,
+    breakpoints: &[String],
+) -> ExitCode {
+    let source = input.unwrap_or(Source::Default);
+    let data = match source.read(&d.dir()) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("{}/{:02}: {}", d.year, d.day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if data.is_example() && matches!(source, Source::Default) {
+        eprintln!("{}/{:02}: No data/input.txt, using data/example.txt", d.year, d.day);
+    }
+
+    println!("Debugging {}/{:02} part {} (type help for commands)", d.year, d.day, part);
+    let mut session = Session::new(io::stdin().lock(), io::stdout().lock(), breakpoints);
+    let mut error = None;
+    let result = debug(&data.text, part, &mut |state| match session.stepped(state) {
+        Ok(go_on) => go_on,
+        Err(e) => {
+            error = Some(e);
+            false
+        }
+    });
+    if let Err(e) = result {
+        eprintln!("{}", e.for_day(d.year, d.day));
+        return ExitCode::FAILURE;
+    }
+    if let Some(e) = error {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    if !session.quit {
+        println!("Finished after {} steps", session.steps);
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Comparison, Condition, Op, Session};
+    use aoc_core::debug::{State, Value};
+
+    /// A machine that counts, a step at a time, in rounds of ten.
+    struct Counter(i64);
+
+    impl State for Counter {
+        fn unit(&self) -> &'static str {
+            "round"
+        }
+
+        fn describe(&self) -> String {
+            format!("count {}", self.0)
+        }
+
+        fn fields(&self) -> Vec<(&'static str, Value)> {
+            let parity = if self.0 % 2 == 0 { "even" } else { "odd" };
+            vec![
+                ("count", self.0.into()),
+                ("round", (self.0 / 10).into()),
+                ("parity", parity.into()),
+            ]
+        }
+
+        fn inspect(&self) -> String {
+            format!("count {} in round {}", self.0, self.0 / 10)
+        }
+    }
+
+    /// Runs the counter to 100 with the given commands, returning what was written.
+    fn session(commands: &str, breakpoints: &[&str]) -> String {
+        let breakpoints: Vec<_> = breakpoints.iter().map(|b| b.to_string()).collect();
+        let mut output = vec![];
+        let mut session = Session::new(commands.as_bytes(), &mut output, &breakpoints);
+        for n in 0..100 {
+            if !session.stepped(&Counter(n)).unwrap() {
+                break;
+            }
+        }
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_condition() {
+        let fields = Counter(7).fields();
+        let condition = Condition::parse("count>=5 && parity == \"odd\"", &fields).unwrap();
+        assert_eq!(
+            Comparison {
+                field: "count".to_string(),
+                op: Op::Ge,
+                value: Value::Int(5),
+            },
+            condition.0[0]
+        );
+        assert_eq!("count >= 5 && parity == odd", condition.to_string());
+        assert!(condition.holds(&fields));
+        assert!(!condition.holds(&Counter(8).fields()));
+        assert!(!condition.holds(&Counter(3).fields()));
+        // Text is never equal to a number.
+        assert!(!Condition::parse("parity == 1", &fields).unwrap().holds(&fields));
+        assert_eq!(
+            Err("No field `x` (try count, round, parity)".to_string()),
+            Condition::parse("x == 1", &fields)
+        );
+        let error = Condition::parse("count", &fields);
+        assert_eq!(Err("No comparison in `count`".to_string()), error);
+    }
+
+    #[test]
+    fn test_session() {
+        let output = session("s\n\ns 3\nn\nb count > 40 && parity == odd\nc\np\np round\nq\n", &[]);
+        let expected = [
+            "[1] count 0",
+            "(debug) [2] count 1",
+            "(debug) [3] count 2",
+            "(debug) [6] count 5",
+            "(debug) [11] count 10",
+            "(debug) Breakpoint 1: count > 40 && parity == odd",
+            "(debug) Breakpoint 1: count > 40 && parity == odd",
+            "[42] count 41",
+            "(debug) count 41 in round 4",
+            "(debug) round = 4",
+            "(debug) ",
+        ];
+        assert_eq!(expected.join("\n"), output);
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        // Breakpoints given up front are checked from the first step.
+        let output = session("c\nd 1\ni\nc\n", &["count == 98", "nope == 1"]);
+        let expected = [
+            "Breakpoint 1: count == 98",
+            "No field `nope` (try count, round, parity)",
+            "[1] count 0",
+            "(debug) Breakpoint 1: count == 98",
+            "[99] count 98",
+            "(debug) (debug) No breakpoints",
+            "(debug) ",
+        ];
+        assert_eq!(expected.join("\n"), output);
+    }
+}
@@ -0,0 +1,311 @@
+//! Extracts example inputs and their answers from a saved copy of a puzzle's page.
+//!
+//! The puzzle text is split into one `<article>` per part. Examples are the `<pre><code>` blocks
+//! in it, and the answers are the emphasised `<code><em>...</em></code>` values. The answer for an
+//! example is taken to be the last one given before the next code block, and part 2's answers
+//! start out belonging to the first example, which part 2 usually reuses.
+
+use crate::answers::{Answers, ANSWERS_FILE};
+use std::fs;
+use std::path::Path;
+
+/// The examples and answers in one part's article, in the order they appear.
+#[derive(Debug, PartialEq)]
+enum Item {
+    /// The index of a distinct `<pre><code>` block.
+    Block(usize),
+    Answer(String),
+}
+
+/// The contents of a puzzle page.
+#[derive(Debug, Default, PartialEq)]
+struct Page {
+    /// Each distinct code block, in the order they first appear.
+    blocks: Vec<String>,
+    /// The items in each part's article.
+    parts: Vec<Vec<Item>>,
+}
+
+/// Replaces the HTML entities that appear in puzzle text.
+fn decode(s: &str) -> String {
+    let mut out = String::new();
+    let mut rest = s;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let Some(end) = rest.find(';') else { break };
+        let c = match &rest[1..end] {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            e => e
+                .strip_prefix('#')
+                .and_then(|n| n.parse().ok())
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The text of some HTML, without its tags.
+fn text(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    while let Some(i) = rest.find('<') {
+        out.push_str(&rest[..i]);
+        rest = rest[i..].find('>').map_or("", |j| &rest[i + j + 1..]);
+    }
+    out.push_str(rest);
+    decode(&out)
+}
+
+impl Page {
+    fn parse(html: &str) -> Page {
+        let mut page = Page::default();
+        for article in html.split("<article").skip(1) {
+            let article = article.split("</article>").next().unwrap();
+            let mut items = vec![];
+            let mut rest = article;
+            loop {
+                let block = rest.find("<pre><code>");
+                let answer = rest.find("<code><em>");
+                match (block, answer) {
+                    (Some(b), a) if a.is_none_or(|a| b < a) => {
+                        rest = &rest[b + "<pre><code>".len()..];
+                        let end = rest.find("</code></pre>").unwrap_or(rest.len());
+                        let mut block = text(&rest[..end]);
+                        if !block.ends_with('\n') {
+                            block.push('\n');
+                        }
+                        let index = match page.blocks.iter().position(|b| *b == block) {
+                            Some(i) => i,
+                            None => {
+                                page.blocks.push(block);
+                                page.blocks.len() - 1
+                            }
+                        };
+                        items.push(Item::Block(index));
+                        rest = &rest[end..];
+                    }
+                    (_, Some(a)) => {
+                        rest = &rest[a + "<code><em>".len()..];
+                        let end = rest.find("</em></code>").unwrap_or(rest.len());
+                        items.push(Item::Answer(text(&rest[..end])));
+                        rest = &rest[end..];
+                    }
+                    _ => break,
+                }
+            }
+            page.parts.push(items);
+        }
+        page
+    }
+
+    /// The answers to each part for each of the chosen blocks, in the same order.
+    fn answers(&self, chosen: &[usize]) -> Vec<[Option<String>; 2]> {
+        let mut answers = vec![[None, None]; chosen.len()];
+        for (part, items) in self.parts.iter().take(2).enumerate() {
+            // Part two usually asks about the page's first example again without repeating it.
+            let mut current = if part == 0 { None } else { chosen.iter().position(|&c| c == 0) };
+            for item in items {
+                match item {
+                    Item::Block(b) => current = chosen.iter().position(|c| c == b),
+                    Item::Answer(a) => {
+                        if let Some(i) = current {
+                            answers[i][part] = Some(a.clone());
+                        }
+                    }
+                }
+            }
+        }
+        answers
+    }
+}
+
+/// The TOML for an answer: a bare integer if it is one, otherwise a string.
+fn toml_value(answer: &str) -> String {
+    match answer.parse::<i64>() {
+        Ok(n) => n.to_string(),
+        Err(_) => toml::Value::String(answer.to_string()).to_string(),
+    }
+}
+
+/// Replaces the `[name]` table in an answers file, or adds it at the end.
+fn set_table(text: &str, name: &str, table: &str) -> String {
+    let header = format!("[{}]", name);
+    let lines: Vec<&str> = text.lines().collect();
+    let Some(start) = lines.iter().position(|l| l.trim() == header) else {
+        let mut out = text.trim_end().to_string();
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+        return out + table;
+    };
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| l.starts_with('['))
+        .map_or(lines.len(), |i| start + 1 + i);
+    let mut out = lines[..start].join("\n");
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(table);
+    if end < lines.len() {
+        out.push('\n');
+        out.push_str(&lines[end..].join("\n"));
+        out.push('\n');
+    }
+    out
+}
+
+/// Lists the code blocks and answers found in the saved puzzle page `page`, then writes the
+/// chosen blocks (numbered from 1, defaulting to the first) to the day's `data/` directory, with
+/// their answers in `data/answers.toml`. A single example is written to `example.txt`, and several
+/// to `example1.txt`, `example2.txt` and so on.
+pub fn extract(day_dir: &Path, page: &Path, blocks: &[u8], force: bool) -> Result<(), String> {
+    let html = fs::read_to_string(page).map_err(|e| format!("{}: {}", page.display(), e))?;
+    let page = Page::parse(&html);
+    if page.blocks.is_empty() {
+        return Err("No <pre><code> blocks found".to_string());
+    }
+
+    let chosen: Vec<usize> = if blocks.is_empty() {
+        vec![0]
+    } else {
+        blocks.iter().map(|&b| b as usize - 1).collect()
+    };
+    if let Some(b) = chosen.iter().find(|&&b| b >= page.blocks.len()) {
+        return Err(format!("No block {} (found {})", b + 1, page.blocks.len()));
+    }
+
+    for (i, block) in page.blocks.iter().enumerate() {
+        let mark = if chosen.contains(&i) { '*' } else { ' ' };
+        let first = block.lines().next().unwrap_or_default();
+        let lines = block.lines().count();
+        println!("{}{:>3}: {} ({} lines)", mark, i + 1, first, lines);
+    }
+
+    let names: Vec<String> = match chosen.len() {
+        1 => vec!["example".to_string()],
+        n => (1..=n).map(|i| format!("example{}", i)).collect(),
+    };
+    let data = day_dir.join("data");
+    for name in &names {
+        let path = data.join(format!("{}.txt", name));
+        if path.exists() && !force {
+            return Err(format!("{} already exists; pass --force to replace it", path.display()));
+        }
+    }
+
+    fs::create_dir_all(&data).map_err(|e| format!("{}: {}", data.display(), e))?;
+    let answers_path = day_dir.join(ANSWERS_FILE);
+    let recorded = Answers::load(day_dir)?;
+    let mut answers_text = fs::read_to_string(&answers_path).unwrap_or_default();
+    for ((name, &b), answers) in names.iter().zip(&chosen).zip(page.answers(&chosen)) {
+        let path = data.join(format!("{}.txt", name));
+        fs::write(&path, &page.blocks[b]).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut table = format!("[{}]\n", name);
+        for (part, answer) in (1..).zip(answers) {
+            // Keep answers the page doesn't give, such as part 2's before part 1 is solved.
+            if let Some(a) = answer.or_else(|| recorded.get(name, part)) {
+                table += &format!("part{} = {}\n", part, toml_value(&a));
+            }
+        }
+        println!("{}: {}", path.display(), table.lines().skip(1).collect::<Vec<_>>().join(", "));
+        answers_text = set_table(&answers_text, name, &table);
+    }
+    fs::write(&answers_path, answers_text).map_err(|e| format!("{}: {}", answers_path.display(), e))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, set_table, Item, Page};
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 20: Pulse Propagation ---</h2>
+<p>For example:</p>
+<pre><code>broadcaster -&gt; a, b
+%a -&gt; b
+</code></pre>
+<p>The result is <code><em>32000000</em></code>.</p>
+<p>Here's a more interesting example:</p>
+<pre><code>broadcaster -&gt; a
+<em>%a</em> -&gt; inv
+</code></pre>
+<p>This gives <code>4</code> low pulses, so <code><em>11687500</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>777666211</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>In the first example, the answer is <code><em>5</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse() {
+        let page = Page::parse(PAGE);
+        assert_eq!(
+            vec!["broadcaster -> a, b\n%a -> b\n", "broadcaster -> a\n%a -> inv\n"],
+            page.blocks
+        );
+        assert_eq!(
+            vec![
+                vec![
+                    Item::Block(0),
+                    Item::Answer("32000000".to_string()),
+                    Item::Block(1),
+                    Item::Answer("11687500".to_string()),
+                ],
+                vec![Item::Answer("5".to_string())],
+            ],
+            page.parts
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let page = Page::parse(PAGE);
+        let s = |s: &str| Some(s.to_string());
+        assert_eq!(
+            vec![[s("32000000"), s("5")], [s("11687500"), None]],
+            page.answers(&[0, 1])
+        );
+        assert_eq!(vec![[s("11687500"), None]], page.answers(&[1]));
+        assert_eq!(
+            vec![[s("11687500"), None], [s("32000000"), s("5")]],
+            page.answers(&[1, 0])
+        );
+        assert_eq!(vec![[s("32000000"), s("5")]], page.answers(&[0]));
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!("a < b && c > 'd' &x", decode("a &lt; b &amp;&amp; c &gt; &#39;d&#39; &x"));
+    }
+
+    #[test]
+    fn test_set_table() {
+        let text = "[input]\npart1 = 1\n\n[example]\npart1 = 2\n";
+        assert_eq!(
+            "[input]\npart1 = 1\n\n[example]\npart1 = 3\npart2 = 4\n",
+            set_table(text, "example", "[example]\npart1 = 3\npart2 = 4\n")
+        );
+        assert_eq!(
+            "[input]\npart1 = 5\n\n[example]\npart1 = 2\n",
+            set_table(text, "input", "[input]\npart1 = 5\n")
+        );
+        assert_eq!("[example]\npart1 = 3\n", set_table("", "example", "[example]\npart1 = 3\n"));
+    }
+}
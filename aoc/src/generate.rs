@@ -0,0 +1,46 @@
+//! The days with random input generators, for scale and stress testing.
+
+/// Generates a random input of roughly the given size from a seed.
+pub type Generator = fn(u64, usize) -> String;
+
+/// Each generator, with its year, day and the size of the real inputs.
+pub const GENERATORS: &[(u16, u8, Generator, usize)] = &[
+    (2023, 14, aoc_2023_14::generate, 100),
+    (2023, 16, aoc_2023_16::generate, 110),
+    (2023, 17, aoc_2023_17::generate, 141),
+    (2023, 19, aoc_2023_19::generate, 550),
+    (2023, 20, aoc_2023_20::generate, 4),
+    (2023, 21, aoc_2023_21::generate, 131),
+    (2023, 22, aoc_2023_22::generate, 1200),
+    (2023, 23, aoc_2023_23::generate, 141),
+];
+
+/// The generator for a day, and the size of the real inputs.
+pub fn find(year: u16, day: u8) -> Option<(Generator, usize)> {
+    GENERATORS
+        .iter()
+        .find(|g| g.0 == year && g.1 == day)
+        .map(|g| (g.2, g.3))
+}
+
+#[cfg(test)]
+mod test {
+    use super::GENERATORS;
+    use crate::registry::DAYS;
+
+    /// Every generator makes inputs that both parts can answer, from the smallest sizes, which
+    /// are raised to the smallest that work, to sizes well past the cap on 2023/20's.
+    #[test]
+    fn test_generators() {
+        for &(year, day, generate, _) in GENERATORS {
+            let d = DAYS.iter().find(|d| d.year == year && d.day == day).unwrap();
+            for size in (0..=5).chain([50]) {
+                let input = generate(1, size);
+                let run = d.run(&[1, 2], &input);
+                let run =
+                    run.unwrap_or_else(|e| panic!("{}/{:02} size {}: {}", year, day, size, e));
+                assert_eq!(2, run.parts.len());
+            }
+        }
+    }
+}
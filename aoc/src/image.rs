@@ -0,0 +1,375 @@
+//! Encodes frames as PPM, PNG or animated GIF images.
+//!
+//! The encoders are small enough to write by hand: PNGs are stored without compression, which
+//! keeps them simple at the cost of size, while GIFs use LZW compression as the format requires.
+
+use aoc_core::render::{Frame, Rgb};
+use clap::ValueEnum;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+#[derive(Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum Format {
+    /// A single animated GIF.
+    #[default]
+    Gif,
+    /// A PNG file for each frame.
+    Png,
+    /// A binary PPM file for each frame.
+    Ppm,
+}
+
+/// The palette index of each pixel, row by row, with each cell drawn `scale` pixels square.
+fn pixels(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(frame.width() * frame.height() * scale * scale);
+    for row in frame.rows() {
+        let start = out.len();
+        for &cell in row {
+            out.extend(std::iter::repeat_n(cell, scale));
+        }
+        let end = out.len();
+        for _ in 1..scale {
+            out.extend_from_within(start..end);
+        }
+    }
+    out
+}
+
+/// A binary (P6) PPM image of the frame.
+pub fn ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    for i in pixels(frame, scale) {
+        out.extend(frame.palette()[i as usize]);
+    }
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for &b in data {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &x in data {
+        a = (a + x as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// Wraps `data` in a zlib stream of stored (uncompressed) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if data.is_empty() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// A PNG image of the frame, using its palette.
+pub fn png(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    let mut header = vec![];
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8-bit palette indices, with the standard compression, filtering and no interlacing.
+    header.extend([8, 3, 0, 0, 0]);
+    png_chunk(&mut out, b"IHDR", &header);
+    png_chunk(&mut out, b"PLTE", &frame.palette().concat());
+
+    // Each row starts with its filter type, which is always none.
+    let mut rows = Vec::with_capacity((width + 1) * height);
+    for row in pixels(frame, scale).chunks(width.max(1)) {
+        rows.push(0);
+        rows.extend(row);
+    }
+    png_chunk(&mut out, b"IDAT", &zlib_stored(&rows));
+    png_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+/// The number of bits a GIF colour table needs for `palette`, and the table itself, padded to
+/// that many bits' worth of colours.
+fn colour_table(palette: &[Rgb]) -> (u8, Vec<u8>) {
+    let bits = (1..=8).find(|&b| palette.len() <= 1 << b).unwrap();
+    let mut table = palette.concat();
+    table.resize(3 << bits, 0);
+    (bits, table)
+}
+
+/// Packs variable-width codes into bytes, least significant bit first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u32,
+    len: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u8) {
+        self.acc |= (code as u32) << self.len;
+        self.len += width;
+        while self.len >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compresses palette indices with GIF's variant of LZW, starting from codes of `min_size` bits.
+fn lzw(min_size: u8, data: &[u8]) -> Vec<u8> {
+    let clear = 1_u16 << min_size;
+    let end = clear + 1;
+    let mut bits = BitWriter::default();
+    let mut dict: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut width = min_size + 1;
+
+    bits.write(clear, width);
+    let mut prefix: Option<u16> = None;
+    for &k in data {
+        prefix = Some(match prefix {
+            None => k as u16,
+            Some(p) => match dict.get(&(p, k)) {
+                Some(&code) => code,
+                None => {
+                    bits.write(p, width);
+                    if next == 4096 {
+                        // The table is full, so start again.
+                        bits.write(clear, width);
+                        dict.clear();
+                        next = end + 1;
+                        width = min_size + 1;
+                    } else {
+                        dict.insert((p, k), next);
+                        next += 1;
+                        // The decoder adds each code a step later, so widens a step later too.
+                        if next > 1 << width {
+                            width += 1;
+                        }
+                    }
+                    k as u16
+                }
+            },
+        });
+    }
+    if let Some(p) = prefix {
+        bits.write(p, width);
+        // The decoder's code for this last string takes it to the next width, if there is one.
+        if next == 1 << width && width < 12 {
+            width += 1;
+        }
+    }
+    bits.write(end, width);
+    bits.finish()
+}
+
+/// Writes an animated GIF, a frame at a time, looping forever.
+pub struct Gif<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+    palette: &'static [Rgb],
+    scale: usize,
+    delay: u16,
+}
+
+impl<W: Write> Gif<W> {
+    /// Starts an animation of frames the size of `first` (which isn't added), with each cell
+    /// drawn `scale` pixels square and each frame shown for `delay` hundredths of a second.
+    pub fn new(mut out: W, first: &Frame, scale: usize, delay: u16) -> io::Result<Self> {
+        let (width, height) = (first.width() * scale, first.height() * scale);
+        if width > u16::MAX as usize || height > u16::MAX as usize {
+            return Err(io::Error::other(format!("{}x{} is too big for a GIF", width, height)));
+        }
+        let (bits, table) = colour_table(first.palette());
+        out.write_all(b"GIF89a")?;
+        out.write_all(&(width as u16).to_le_bytes())?;
+        out.write_all(&(height as u16).to_le_bytes())?;
+        // A global colour table, with its colour resolution and size, then the background colour
+        // and pixel aspect ratio.
+        out.write_all(&[0x80 | (bits - 1) << 4 | (bits - 1), 0, 0])?;
+        out.write_all(&table)?;
+        // Loop forever.
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+        Ok(Gif {
+            out,
+            width,
+            height,
+            palette: first.palette(),
+            scale,
+            delay,
+        })
+    }
+
+    pub fn add(&mut self, frame: &Frame) -> io::Result<()> {
+        let (width, height) = (frame.width() * self.scale, frame.height() * self.scale);
+        if (width, height) != (self.width, self.height) {
+            return Err(io::Error::other(format!(
+                "Frame is {}x{}, not {}x{}",
+                width, height, self.width, self.height
+            )));
+        }
+        // Graphic control: leave the frame in place, and show it for the delay.
+        self.out.write_all(&[0x21, 0xf9, 0x04, 0x04])?;
+        self.out.write_all(&self.delay.to_le_bytes())?;
+        self.out.write_all(&[0, 0])?;
+
+        self.out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&(width as u16).to_le_bytes())?;
+        self.out.write_all(&(height as u16).to_le_bytes())?;
+        let (bits, table) = colour_table(frame.palette());
+        if frame.palette() == self.palette {
+            self.out.write_all(&[0])?;
+        } else {
+            self.out.write_all(&[0x80 | (bits - 1)])?;
+            self.out.write_all(&table)?;
+        }
+
+        let min_size = bits.max(2);
+        self.out.write_all(&[min_size])?;
+        for block in lzw(min_size, &pixels(frame, self.scale)).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+        self.out.write_all(&[0])
+    }
+
+    /// Ends the animation, returning the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{crc32, lzw, png, ppm, Gif};
+    use aoc_core::render::{Frame, Rgb};
+
+    const PALETTE: &[Rgb] = &[[0, 0, 0], [255, 0, 0], [0, 255, 0], [0, 0, 255], [9, 9, 9]];
+
+    fn frame() -> Frame {
+        let mut frame = Frame::new(3, 2, PALETTE);
+        frame.set(1, 0, 1);
+        frame.set(2, 1, 4);
+        frame
+    }
+
+    /// Decodes GIF LZW data the way decoders do, to check the encoder against.
+    fn unlzw(min_size: u8, bytes: &[u8]) -> Vec<u8> {
+        let clear = 1_usize << min_size;
+        let (mut pos, mut width) = (0, min_size as usize + 1);
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut prev: Option<Vec<u8>> = None;
+        let mut out = vec![];
+        loop {
+            let code = (0..width).fold(0, |c, i| {
+                let bit = (bytes[(pos + i) / 8] >> ((pos + i) % 8)) & 1;
+                c | (bit as usize) << i
+            });
+            pos += width;
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                width = min_size as usize + 1;
+                prev = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+            let entry = match (table.get(code), &prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(p)) => [&p[..], &p[..1]].concat(),
+                (None, None) => panic!("Bad code {}", code),
+            };
+            if let Some(p) = prev {
+                if table.len() < 4096 {
+                    table.push([&p[..], &entry[..1]].concat());
+                    if table.len() == 1 << width && width < 12 {
+                        width += 1;
+                    }
+                }
+            }
+            out.extend(&entry);
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw() {
+        let mut data: Vec<u8> = (0..20000_u32).map(|i| (i * i / 7 % 5) as u8).collect();
+        data.extend([3; 5000]);
+        for len in [0, 1, 2, 5, 13, 100, 1000, 25000] {
+            assert_eq!(data[..len], unlzw(3, &lzw(3, &data[..len])));
+        }
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = ppm(&frame(), 2);
+        assert!(image.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(11 + 6 * 4 * 3, image.len());
+        assert_eq!([255, 0, 0], image[11 + 2 * 3..][..3]);
+    }
+
+    #[test]
+    fn test_png() {
+        let image = png(&frame(), 1);
+        assert!(image.starts_with(b"\x89PNG\r\n\x1a\n"));
+        // The header chunk holds the size, and ends with its CRC.
+        assert_eq!(b"IHDR", &image[12..16]);
+        assert_eq!([0, 0, 0, 3, 0, 0, 0, 2], image[16..24]);
+        let crc = u32::from_be_bytes(image[29..33].try_into().unwrap());
+        assert_eq!(crc32(&image[12..29]), crc);
+        assert!(image.ends_with(b"IEND\xae\x42\x60\x82"));
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut gif = Gif::new(vec![], &frame(), 2, 5).unwrap();
+        gif.add(&frame()).unwrap();
+        assert!(gif.add(&Frame::new(4, 2, PALETTE)).is_err());
+        let image = gif.finish().unwrap();
+        assert!(image.starts_with(b"GIF89a\x06\x00\x04\x00"));
+        assert_eq!(Some(&0x3b), image.last());
+    }
+}
@@ -0,0 +1,53 @@
+//! Checks the layout of each day's saved inputs against the shape the day expects.
+
+use crate::registry::Entry;
+use crate::verify::input_names;
+use aoc_core::lint::{self, Issue};
+use std::fs;
+use std::process::ExitCode;
+
+/// Prints an input's issues, each prefixed with `name` and the shape the day expects.
+pub fn report(d: &Entry, name: &str, issues: &[Issue]) {
+    for issue in issues {
+        eprintln!("{}/{:02} {} ({}): {}", d.year, d.day, name, d.shape, issue);
+    }
+}
+
+/// Lints the real input and examples in each day's `data/` directory, optionally rewriting them
+/// with the fixable issues fixed. Fails if any issues are left.
+pub fn lint(days: &[&Entry], fix: bool) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    let (mut files, mut fixed) = (0, 0);
+    for d in days {
+        for name in input_names(&d.dir()) {
+            let path = d.dir().join("data").join(format!("{}.txt", name));
+            let text = match fs::read_to_string(&path) {
+                Ok(text) => text,
+                Err(e) => {
+                    eprintln!("{}: {}", path.display(), e);
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+            files += 1;
+            let mut issues = lint::lint(&text, d.shape);
+            if fix && issues.iter().any(Issue::fixable) {
+                let normalised = lint::normalise(&text, d.shape);
+                if let Err(e) = fs::write(&path, &normalised) {
+                    eprintln!("{}: {}", path.display(), e);
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+                fixed += 1;
+                println!("Fixed {}", path.display());
+                issues = lint::lint(&normalised, d.shape);
+            }
+            if !issues.is_empty() {
+                report(d, &name, &issues);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    println!("Checked {} inputs, fixed {}", files, fixed);
+    status
+}
@@ -0,0 +1,457 @@
+//! Runs Advent of Code solutions for any year and day.
+
+mod all;
+mod alloc;
+#[cfg(unix)]
+mod animate;
+mod answers;
+mod bench;
+mod debug;
+mod examples;
+mod generate;
+mod image;
+mod lint;
+mod output;
+mod registry;
+mod render;
+mod scaffold;
+mod verify;
+
+use aoc_core::input::Source;
+use clap::{Parser, Subcommand};
+use output::{Format, Record};
+use registry::{workspace_dir, Entry, DAYS};
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions, optionally filtered by year, day and part.
+    Run {
+        /// Only run solutions from this year.
+        year: Option<u16>,
+        /// Only run this day (requires a year).
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// Only run this part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from this file, or from stdin for `-`. By default the input
+        /// is read from the day's data/input.txt, falling back to data/example.txt.
+        #[arg(long, requires = "day", value_parser = |s: &str| Ok::<_, String>(Source::from_arg(s)))]
+        input: Option<Source>,
+        /// How to print the answers: json and tsv give one record per part, with its timing and
+        /// the input's hash.
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Fix the input's line endings and stray whitespace before solving, rather than only
+        /// warning about them.
+        #[arg(long)]
+        normalise: bool,
+        /// Show what each part reports about its search, such as the states it expanded, after
+        /// its answer in text or as a `stats` object in JSON.
+        #[arg(long)]
+        stats: bool,
+        /// Show how many allocations each part makes, the bytes they ask for in all, and the
+        /// most bytes the part holds at once.
+        #[arg(long)]
+        memory: bool,
+    },
+    /// Run every part of the solutions in parallel and summarise the answers, checking them
+    /// against the recorded ones.
+    All {
+        /// Only run solutions from this year.
+        year: Option<u16>,
+        /// Only run this day (requires a year).
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// How many parts to run at once. Defaults to the number of CPUs.
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+        /// Stop any part still running after this many seconds.
+        #[arg(long, default_value = "60", value_parser = seconds)]
+        timeout: Duration,
+    },
+    /// Time the parse, part 1 and part 2 phases of solutions against their inputs.
+    Bench {
+        /// Only time solutions from this year.
+        year: Option<u16>,
+        /// Only time this day (requires a year).
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// How many times to run each solution.
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Flag phases whose median time is this many percent slower than the last recorded run.
+        #[arg(long, default_value_t = 20.0)]
+        threshold: f64,
+        /// The JSON file to append results to, and compare them with.
+        #[arg(long, default_value_os_t = registry::workspace_dir().join("bench-history.json"))]
+        history: PathBuf,
+    },
+    /// Check each day's saved inputs for stray whitespace, line ending and layout problems.
+    Lint {
+        /// Only check inputs from this year.
+        year: Option<u16>,
+        /// Only check this day (requires a year).
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// Rewrite the inputs with every problem that can be fixed safely fixed.
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Check solutions against the answers recorded in each day's data/answers.toml.
+    Verify {
+        /// Only check solutions from this year.
+        year: Option<u16>,
+        /// Only check this day (requires a year).
+        #[arg(requires = "year")]
+        day: Option<u8>,
+    },
+    /// Create the crate for a new day, with a solution skeleton, and register it with the runner.
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Print a random input for a day, for scale and stress testing.
+    Generate {
+        year: u16,
+        day: u8,
+        /// The seed for the random number generator, so inputs can be reproduced.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big an input to make: the width of a grid, or the number of bricks, workflows or
+        /// counters. Defaults to the size of the real inputs.
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Save pictures of a solution at work, or play them in the terminal, for the days that can
+    /// draw themselves.
+    Render {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from this file, or from stdin for `-`, rather than the day's
+        /// data/input.txt.
+        #[arg(long, value_parser = |s: &str| Ok::<_, String>(Source::from_arg(s)))]
+        input: Option<Source>,
+        /// Save an animated GIF, or a PNG or PPM image of each frame.
+        #[arg(long, value_enum, default_value_t)]
+        format: image::Format,
+        /// The GIF to write, or the directory for the frames. Defaults to `<year>-<day>.gif`, or
+        /// `<year>-<day>/`, in the current directory.
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// How many pixels square to draw each cell.
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: u32,
+        /// Keep only every this many frames (and the last), for long animations.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        every: u32,
+        /// How long to show each frame of a GIF or animation, in hundredths of a second.
+        #[arg(long, default_value_t = 4)]
+        delay: u16,
+        /// Play the frames in the terminal rather than saving them. Space pauses, n steps while
+        /// paused, + and - change the speed, the arrow keys scroll and q quits.
+        #[arg(long, conflicts_with_all = ["format", "output", "scale"])]
+        animate: bool,
+    },
+    /// Step through a day's machine interactively, for the days that run one: 2022/10's CPU,
+    /// 2022/11's monkeys and 2023/20's pulses.
+    Debug {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from this file, rather than the day's data/input.txt.
+        #[arg(long, value_parser = |s: &str| Ok::<_, String>(Source::from_arg(s)))]
+        input: Option<Source>,
+        /// Run the machine as this part does.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Stop whenever this condition holds, such as `x == 21`, `to == zh && pulse == high` or
+        /// `monkey == 3 && item > 70`. Can be given more than once.
+        #[arg(long = "break", value_name = "CONDITION")]
+        breakpoints: Vec<String>,
+    },
+    /// Save the examples and their answers from a locally saved copy of a puzzle's page.
+    Examples {
+        year: u16,
+        day: u8,
+        /// The saved puzzle page.
+        page: PathBuf,
+        /// Which of the page's code blocks to save, numbered from 1. Several blocks are saved
+        /// as data/example1.txt, data/example2.txt and so on.
+        #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(u8).range(1..))]
+        blocks: Vec<u8>,
+        /// Replace example files that already exist.
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// A positive number of seconds, which may be fractional.
+fn seconds(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s.parse().map_err(|_| format!("{:?} isn't a number of seconds", s))?;
+    if secs <= 0.0 {
+        return Err("must be more than 0 seconds".to_string());
+    }
+    Duration::try_from_secs_f64(secs).map_err(|e| e.to_string())
+}
+
+/// The solutions matching the filters, or `None` (after reporting it) if there aren't any.
+fn selected(year: Option<u16>, day: Option<u8>) -> Option<Vec<&'static Entry>> {
+    let days: Vec<_> = DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|y| d.year == y))
+        .filter(|d| day.is_none_or(|n| d.day == n))
+        .collect();
+    if days.is_empty() {
+        eprintln!("No matching solutions");
+        return None;
+    }
+    Some(days)
+}
+
+fn run(
+    days: &[&Entry],
+    part: Option<u8>,
+    input: Option<Source>,
+    format: Format,
+    normalise: bool,
+    stats: bool,
+    memory: bool,
+) -> ExitCode {
+    if memory {
+        alloc::track();
+    }
+    let source = input.unwrap_or(Source::Default);
+    let mut status = ExitCode::SUCCESS;
+    if let Some(header) = format.header() {
+        println!("{}", header);
+    }
+    for d in days {
+        let data = match source.read(&d.dir()) {
+            Ok(data) => data,
+            Err(e) => {
+                eprintln!("{}/{:02}: {}", d.year, d.day, e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        if data.is_example() && matches!(source, Source::Default) {
+            eprintln!("{}/{:02}: No data/input.txt, using data/example.txt", d.year, d.day);
+        }
+        let parts = part.map_or(vec![1, 2], |p| vec![p]);
+        let issues = aoc_core::lint::lint(&data.text, d.shape);
+        if !issues.is_empty() {
+            let name = data.path.as_ref().and_then(|p| p.file_stem());
+            let name = name.map_or("stdin".into(), |n| n.to_string_lossy());
+            lint::report(d, &name, &issues);
+        }
+        let text = match normalise {
+            true => Cow::Owned(aoc_core::lint::normalise(&data.text, d.shape)),
+            false => Cow::Borrowed(&data.text),
+        };
+        let run = match stats || memory {
+            true => d.run_instrumented(&parts, &text),
+            false => d.run(&parts, &text),
+        };
+        let run = match run {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("{}", e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let input_path = data
+            .path
+            .as_deref()
+            .map(|p| p.strip_prefix(workspace_dir()).unwrap_or(p));
+        for (i, (&p, (answer, time))) in parts.iter().zip(&run.parts).enumerate() {
+            let mut record = Record::new(d.year, d.day, p, answer, *time, run.parse)
+                .input(input_path, &data.text);
+            if stats {
+                record = record.stats(&run.stats[i]);
+            }
+            if memory {
+                record = record.memory(run.memory[i]);
+            }
+            println!("{}", format.format(&record));
+        }
+    }
+    status
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            format,
+            normalise,
+            stats,
+            memory,
+        } => selected(year, day).map_or(ExitCode::FAILURE, |days| {
+            run(&days, part, input, format, normalise, stats, memory)
+        }),
+        Command::All {
+            year,
+            day,
+            jobs,
+            timeout,
+        } => selected(year, day).map_or(ExitCode::FAILURE, |days| {
+            let jobs = jobs.map_or_else(
+                || thread::available_parallelism().map_or(1, |n| n.get()),
+                |j| j as usize,
+            );
+            all::all(&days, jobs, timeout)
+        }),
+        Command::Bench {
+            year,
+            day,
+            iterations,
+            threshold,
+            history,
+        } => selected(year, day).map_or(ExitCode::FAILURE, |days| {
+            bench::bench(&days, iterations, threshold, &history)
+        }),
+        Command::Lint { year, day, fix } => {
+            selected(year, day).map_or(ExitCode::FAILURE, |days| lint::lint(&days, fix))
+        }
+        Command::Verify { year, day } => {
+            selected(year, day).map_or(ExitCode::FAILURE, |days| verify::verify(&days))
+        }
+        Command::New { year, day } => match scaffold::new_day(year, day) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+        } => match generate::find(year, day) {
+            Some((generate, default)) => {
+                print!("{}", generate(seed, size.unwrap_or(default)));
+                ExitCode::SUCCESS
+            }
+            None => {
+                let days: Vec<_> = generate::GENERATORS
+                    .iter()
+                    .map(|g| format!("{}/{:02}", g.0, g.1))
+                    .collect();
+                eprintln!("No generator for {}/{:02} (try {})", year, day, days.join(", "));
+                ExitCode::FAILURE
+            }
+        },
+        Command::Render {
+            year,
+            day,
+            input,
+            format,
+            output,
+            scale,
+            every,
+            delay,
+            animate,
+        } => match (selected(Some(year), Some(day)), render::find(year, day)) {
+            #[cfg(unix)]
+            (Some(days), Some(visualise)) if animate => {
+                let options = animate::Options {
+                    every: every as usize,
+                    delay,
+                };
+                animate::animate(days[0], visualise, input, &options)
+            }
+            #[cfg(not(unix))]
+            (Some(_), Some(_)) if animate => {
+                eprintln!("--animate needs a Unix terminal");
+                ExitCode::FAILURE
+            }
+            (Some(days), Some(visualise)) => {
+                let options = render::Options {
+                    format,
+                    scale: scale as usize,
+                    every: every as usize,
+                    delay,
+                };
+                render::render(days[0], visualise, input, output, &options)
+            }
+            (Some(_), None) => {
+                let days: Vec<_> = render::VISUALISERS
+                    .iter()
+                    .map(|v| format!("{}/{:02}", v.0, v.1))
+                    .collect();
+                eprintln!("No visualisation for {}/{:02} (try {})", year, day, days.join(", "));
+                ExitCode::FAILURE
+            }
+            (None, _) => ExitCode::FAILURE,
+        },
+        Command::Debug {
+            year,
+            day,
+            input,
+            part,
+            breakpoints,
+        } => match (selected(Some(year), Some(day)), debug::find(year, day)) {
+            (Some(days), Some(debugger)) => {
+                debug::debug(days[0], debugger, input, part, &breakpoints)
+            }
+            (Some(_), None) => {
+                let days: Vec<_> = debug::DEBUGGERS
+                    .iter()
+                    .map(|v| format!("{}/{:02}", v.0, v.1))
+                    .collect();
+                eprintln!("No debugger for {}/{:02} (try {})", year, day, days.join(", "));
+                ExitCode::FAILURE
+            }
+            (None, _) => ExitCode::FAILURE,
+        },
+        Command::Examples {
+            year,
+            day,
+            page,
+            blocks,
+            force,
+        } => match selected(Some(year), Some(day)) {
+            Some(days) => match examples::extract(&days[0].dir(), &page, &blocks, force) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    ExitCode::FAILURE
+                }
+            },
+            None => ExitCode::FAILURE,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::seconds;
+    use std::time::Duration;
+
+    #[test]
+    fn test_seconds() {
+        assert_eq!(Ok(Duration::from_millis(1500)), seconds("1.5"));
+        assert_eq!(Ok(Duration::from_secs(60)), seconds("60"));
+        for bad in ["0", "-1", "nan", "inf", "1e30", "soon"] {
+            assert!(seconds(bad).is_err(), "{} was accepted", bad);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Formats the answers from `run`, either for people or as records for scripts to consume.
+
+use crate::alloc::Usage;
+use aoc_core::stats::{Stat, Stats};
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// One answer per line, as `2023/17 part 1: 102`.
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+    /// Tab-separated values, with a header line.
+    Tsv,
+}
+
+/// The answer to one part, with where it came from and how long it took.
+#[derive(Serialize)]
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+    /// How long this part took, not counting parsing.
+    pub time_ns: u64,
+    /// How long the input took to parse, which both parts share.
+    pub parse_ns: u64,
+    /// The file the input was read from, or `-` for standard input.
+    pub input: String,
+    /// The SHA-256 of the input, in hex, to tell which input an answer belongs to.
+    pub input_sha256: String,
+    /// What the part reported about its search, if asked for. Left out of TSV.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "stats_json")]
+    pub stats: Option<&'a Stats>,
+    /// What the part allocated, if asked for. Left out of TSV.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Usage>,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &'a str,
+        time: Duration,
+        parse: Duration,
+    ) -> Self {
+        Record {
+            year,
+            day,
+            part,
+            answer,
+            time_ns: time.as_nanos() as u64,
+            parse_ns: parse.as_nanos() as u64,
+            input: String::new(),
+            input_sha256: String::new(),
+            stats: None,
+            memory: None,
+        }
+    }
+
+    /// Adds what the part allocated.
+    pub fn memory(self, memory: Usage) -> Self {
+        Record {
+            memory: Some(memory),
+            ..self
+        }
+    }
+
+    /// Adds what the part reported about its search.
+    pub fn stats(self, stats: &'a Stats) -> Self {
+        Record {
+            stats: Some(stats),
+            ..self
+        }
+    }
+
+    /// Fills in which input the answer is for.
+    pub fn input(self, path: Option<&Path>, text: &str) -> Self {
+        Record {
+            input: path.map_or("-".to_string(), |p| p.display().to_string()),
+            input_sha256: hash(text),
+            ..self
+        }
+    }
+}
+
+/// Each stat as a number, or an object for a histogram.
+fn stats_json<S: Serializer>(stats: &Option<&Stats>, serializer: S) -> Result<S::Ok, S::Error> {
+    let stats = stats.unwrap();
+    let value: serde_json::Map<_, _> = stats
+        .iter()
+        .map(|(name, stat)| {
+            let value = match stat {
+                Stat::Count(n) | Stat::Max(n) => json!(n),
+                Stat::Histogram(h) => json!({
+                    "count": h.count(),
+                    "min": h.min(),
+                    "mean": h.mean(),
+                    "max": h.max(),
+                    "buckets": h.buckets().map(|b| json!([b.0, b.1, b.2])).collect::<Vec<_>>(),
+                }),
+            };
+            (name.to_string(), value)
+        })
+        .collect();
+    value.serialize(serializer)
+}
+
+/// A number of bytes, in the largest binary unit that keeps it at least 1.
+fn bytes(n: u64) -> String {
+    let units = ["bytes", "KiB", "MiB", "GiB", "TiB"];
+    let i = ((63 - n.max(1).leading_zeros()) / 10).min(4) as usize;
+    match i {
+        0 => format!("{} {}", n, units[0]),
+        _ => format!("{:.1} {}", n as f64 / (1u64 << (10 * i)) as f64, units[i]),
+    }
+}
+
+fn hash(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+const TSV_HEADER: &str = "year\tday\tpart\tanswer\ttime_ns\tparse_ns\tinput\tinput_sha256";
+
+/// Escapes a TSV field, so multi-line answers (like 2022/10's CRT image) stay on one line.
+fn tsv_field(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+impl Format {
+    /// The line to print before any records, if the format has one.
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Tsv => Some(TSV_HEADER),
+            _ => None,
+        }
+    }
+
+    pub fn format(self, r: &Record) -> String {
+        match self {
+            Format::Text => {
+                let part = format!("{}/{:02} part {}", r.year, r.day, r.part);
+                let mut text = match r.answer.contains('\n') {
+                    true => format!("{}:\n{}", part, r.answer.trim_end()),
+                    false => format!("{}: {}", part, r.answer),
+                };
+                if let Some(m) = r.memory {
+                    let (total, peak) = (bytes(m.bytes), bytes(m.peak_bytes));
+                    let usage = format!("{} allocations, {} in all", m.allocations, total);
+                    let _ = write!(text, "\n  {}, {} at peak", usage, peak);
+                }
+                if let Some(stats) = r.stats {
+                    for line in stats.to_string().lines() {
+                        text.push_str("\n  ");
+                        text.push_str(line);
+                    }
+                }
+                text
+            }
+            Format::Json => serde_json::to_string(r).unwrap(),
+            Format::Tsv => [
+                r.year.to_string(),
+                r.day.to_string(),
+                r.part.to_string(),
+                tsv_field(r.answer),
+                r.time_ns.to_string(),
+                r.parse_ns.to_string(),
+                tsv_field(&r.input),
+                r.input_sha256.clone(),
+            ]
+            .join("\t"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bytes, Format, Record};
+    use crate::alloc::Usage;
+    use aoc_core::stats::{self, Histogram};
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn record(answer: &str) -> Record<'_> {
+        Record::new(2022, 10, 2, answer, Duration::from_micros(3), Duration::from_micros(2))
+            .input(Some(Path::new("2022/10/data/input.txt")), "noop\n")
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!("2022/10 part 2: 13140", Format::Text.format(&record("13140")));
+        assert_eq!("2022/10 part 2:\n##..\n#..#", Format::Text.format(&record("##..\n#..#\n")));
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&Format::Json.format(&record("##..\n#..#\n"))).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "year": 2022,
+                "day": 10,
+                "part": 2,
+                "answer": "##..\n#..#\n",
+                "time_ns": 3000,
+                "parse_ns": 2000,
+                "input": "2022/10/data/input.txt",
+                "input_sha256": "f42479bb812791672351969841b5817302ec3f87c7f1f803874fc86156266eb3",
+            }),
+            json
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let ((), stats) = stats::collect(|| {
+            stats::count("trials", 2);
+            let mut cuts = Histogram::new();
+            [3, 5].into_iter().for_each(|v| cuts.add(v));
+            stats::histogram("cuts", &cuts);
+        });
+        let record = record("54").stats(&stats);
+        let text = [
+            "2022/10 part 2: 54",
+            "  trials  2",
+            "  cuts    2 values, min 3, mean 4.0, max 5",
+            "            2-3: 1",
+            "            4-7: 1",
+        ];
+        assert_eq!(text.join("\n"), Format::Text.format(&record));
+        let json: serde_json::Value = serde_json::from_str(&Format::Json.format(&record)).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "trials": 2,
+                "cuts": {
+                    "count": 2,
+                    "min": 3,
+                    "mean": 4.0,
+                    "max": 5,
+                    "buckets": [[2, 3, 1], [4, 7, 1]],
+                },
+            }),
+            json["stats"]
+        );
+    }
+
+    #[test]
+    fn test_memory() {
+        assert_eq!(
+            ["0 bytes", "1023 bytes", "1.0 KiB", "1.5 MiB"],
+            [0, 1023, 1024, 3 << 19].map(bytes)
+        );
+        let usage = Usage {
+            allocations: 12,
+            bytes: 5000,
+            peak_bytes: 2048,
+        };
+        let record = record("54").memory(usage);
+        assert_eq!(
+            "2022/10 part 2: 54\n  12 allocations, 4.9 KiB in all, 2.0 KiB at peak",
+            Format::Text.format(&record)
+        );
+        let json: serde_json::Value = serde_json::from_str(&Format::Json.format(&record)).unwrap();
+        assert_eq!(
+            serde_json::json!({"allocations": 12, "bytes": 5000, "peak_bytes": 2048}),
+            json["memory"]
+        );
+    }
+
+    #[test]
+    fn test_tsv() {
+        let fields: Vec<String> = Format::Tsv
+            .format(&record("##..\n#..#\n"))
+            .split('\t')
+            .map(String::from)
+            .collect();
+        assert_eq!(Format::Tsv.header().unwrap().split('\t').count(), fields.len());
+        assert_eq!("##..\\n#..#\\n", fields[3]);
+    }
+}
@@ -0,0 +1,176 @@
+//! The list of every solution linked into the runner.
+
+use crate::alloc::{self, Usage};
+use aoc_core::lint::Shape;
+use aoc_core::parse::ParseError;
+use aoc_core::stats::{self, Stats};
+use aoc_core::Solution;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// A single day's solution.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// The layout the day's input should have.
+    pub shape: Shape,
+    solve: fn(&str, &[u8], bool) -> Result<Run, ParseError>,
+}
+
+/// The result of running a solution once: how long parsing took, and the answer to each part
+/// with how long it took.
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<(String, Duration)>,
+    /// What each part reported to `aoc_core::stats`, if they were collected.
+    pub stats: Vec<Stats>,
+    /// What each part allocated, if it was measured and allocations are being tracked.
+    pub memory: Vec<Usage>,
+}
+
+impl Entry {
+    /// The day's crate directory, which holds its `data/` files.
+    pub fn dir(&self) -> PathBuf {
+        workspace_dir()
+            .join(self.year.to_string())
+            .join(format!("{:02}", self.day))
+    }
+
+    /// Parses the input once and runs the given parts (1 or 2) against it, in turn. Fails,
+    /// without running either part, if the input can't be parsed.
+    pub fn run(&self, parts: &[u8], input: &str) -> Result<Run, ParseError> {
+        (self.solve)(input, parts, false).map_err(|e| e.for_day(self.year, self.day))
+    }
+
+    /// Like `run`, but also collects the stats each part reports and measures what it allocates.
+    /// Their timings include the cost of collecting.
+    pub fn run_instrumented(&self, parts: &[u8], input: &str) -> Result<Run, ParseError> {
+        (self.solve)(input, parts, true).map_err(|e| e.for_day(self.year, self.day))
+    }
+}
+
+/// The root of the workspace, which holds a directory for each year.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8], collect: bool) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let parsed = black_box(S::parse(input))?;
+    let parse = start.elapsed();
+    let answer = |part| match part {
+        1 => black_box(S::part1(&parsed)).to_string(),
+        2 => black_box(S::part2(&parsed)).to_string(),
+        _ => unreachable!("Bad part"),
+    };
+    let mut run = Run {
+        parse,
+        parts: vec![],
+        stats: vec![],
+        memory: vec![],
+    };
+    for &part in parts {
+        let start = Instant::now();
+        let ((answer, memory), stats) = match collect {
+            true => stats::collect(|| alloc::measure(|| answer(part))),
+            false => ((answer(part), Usage::default()), Stats::default()),
+        };
+        run.parts.push((answer, start.elapsed()));
+        run.stats.push(stats);
+        run.memory.push(memory);
+    }
+    Ok(run)
+}
+
+macro_rules! days {
+    ($($year:literal $day:literal => $krate:ident,)*) => {
+        pub const DAYS: &[Entry] = &[$(
+            Entry {
+                year: $year,
+                day: $day,
+                shape: <$krate::Day as Solution>::SHAPE,
+                solve: solve::<$krate::Day>,
+            },
+        )*];
+    };
+}
+
+days! {
+    2021 1 => aoc_2021_01,
+    2021 2 => aoc_2021_02,
+    2021 3 => aoc_2021_03,
+    2021 4 => aoc_2021_04,
+    2021 5 => aoc_2021_05,
+    2021 6 => aoc_2021_06,
+    2021 7 => aoc_2021_07,
+    2021 8 => aoc_2021_08,
+    2022 1 => aoc_2022_01,
+    2022 2 => aoc_2022_02,
+    2022 3 => aoc_2022_03,
+    2022 4 => aoc_2022_04,
+    2022 5 => aoc_2022_05,
+    2022 6 => aoc_2022_06,
+    2022 7 => aoc_2022_07,
+    2022 8 => aoc_2022_08,
+    2022 9 => aoc_2022_09,
+    2022 10 => aoc_2022_10,
+    2022 11 => aoc_2022_11,
+    2022 12 => aoc_2022_12,
+    2023 1 => aoc_2023_01,
+    2023 2 => aoc_2023_02,
+    2023 3 => aoc_2023_03,
+    2023 4 => aoc_2023_04,
+    2023 5 => aoc_2023_05,
+    2023 6 => aoc_2023_06,
+    2023 7 => aoc_2023_07,
+    2023 8 => aoc_2023_08,
+    2023 9 => aoc_2023_09,
+    2023 10 => aoc_2023_10,
+    2023 11 => aoc_2023_11,
+    2023 12 => aoc_2023_12,
+    2023 13 => aoc_2023_13,
+    2023 14 => aoc_2023_14,
+    2023 15 => aoc_2023_15,
+    2023 16 => aoc_2023_16,
+    2023 17 => aoc_2023_17,
+    2023 18 => aoc_2023_18,
+    2023 19 => aoc_2023_19,
+    2023 20 => aoc_2023_20,
+    2023 21 => aoc_2023_21,
+    2023 22 => aoc_2023_22,
+    2023 23 => aoc_2023_23,
+    2023 24 => aoc_2023_24,
+    2023 25 => aoc_2023_25,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{workspace_dir, DAYS};
+    use std::fs;
+
+    /// The inputs in `fuzz/regressions/parse_<year>_<day>/`, which once made a day's parser
+    /// panic, have to parse or fail with an error somewhere within the input.
+    #[test]
+    fn test_fuzz_regressions() {
+        let regressions = workspace_dir().join("fuzz/regressions");
+        for target in fs::read_dir(regressions).unwrap() {
+            let target = target.unwrap();
+            let name = target.file_name().into_string().unwrap();
+            let entry = DAYS
+                .iter()
+                .find(|d| name == format!("parse_{}_{:02}", d.year, d.day))
+                .unwrap_or_else(|| panic!("No day for {}", name));
+            for file in fs::read_dir(target.path()).unwrap() {
+                let path = file.unwrap().path();
+                let input = fs::read_to_string(&path).unwrap();
+                if let Err(e) = entry.run(&[], &input) {
+                    let lines: Vec<&str> = input.lines().collect();
+                    assert!(e.line <= lines.len() + 1, "{}: {}", path.display(), e);
+                    let width = lines.get(e.line - 1).map_or(0, |l| l.chars().count());
+                    assert!(e.column <= width + 1, "{}: {}", path.display(), e);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Saves pictures of a solution at work, for the days that can draw themselves.
+
+use crate::image::{self, Format, Gif};
+use crate::registry::Entry;
+use aoc_core::input::Source;
+use aoc_core::parse::ParseError;
+use aoc_core::render::Frame;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Draws a day's solution at work on the given input, a frame at a time.
+pub type Visualiser = fn(&str, &mut dyn FnMut(&Frame)) -> Result<(), ParseError>;
+
+/// Each day that can draw itself, with its year and day.
+pub const VISUALISERS: &[(u16, u8, Visualiser)] = &[
+    (2022, 5, aoc_2022_05::visualise),
+    (2022, 9, aoc_2022_09::visualise),
+    (2022, 10, aoc_2022_10::visualise),
+    (2023, 14, aoc_2023_14::visualise),
+    (2023, 16, aoc_2023_16::visualise),
+    (2023, 17, aoc_2023_17::visualise),
+    (2023, 21, aoc_2023_21::visualise),
+    (2023, 22, aoc_2023_22::visualise),
+];
+
+/// The visualiser for a day.
+pub fn find(year: u16, day: u8) -> Option<Visualiser> {
+    VISUALISERS
+        .iter()
+        .find(|v| v.0 == year && v.1 == day)
+        .map(|v| v.2)
+}
+
+/// How to save the frames.
+pub struct Options {
+    pub format: Format,
+    /// How many pixels square to draw each cell.
+    pub scale: usize,
+    /// Keep only every this many frames, and the last.
+    pub every: usize,
+    /// How long to show each frame of a GIF, in hundredths of a second.
+    pub delay: u16,
+}
+
+/// Writes frames to an animated GIF, or to numbered files in a directory.
+struct Writer<'a> {
+    path: &'a Path,
+    options: &'a Options,
+    gif: Option<Gif<BufWriter<File>>>,
+    written: usize,
+}
+
+impl Writer<'_> {
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let scale = self.options.scale;
+        match self.options.format {
+            Format::Gif => {
+                if self.gif.is_none() {
+                    let out = BufWriter::new(File::create(self.path)?);
+                    self.gif = Some(Gif::new(out, frame, scale, self.options.delay)?);
+                }
+                self.gif.as_mut().unwrap().add(frame)?;
+            }
+            Format::Png | Format::Ppm => {
+                let (ext, image) = match self.options.format {
+                    Format::Png => ("png", image::png(frame, scale)),
+                    _ => ("ppm", image::ppm(frame, scale)),
+                };
+                let name = format!("frame-{:05}.{}", self.written, ext);
+                fs::write(self.path.join(name), image)?;
+            }
+        }
+        self.written += 1;
+        Ok(())
+    }
+
+    fn finish(self) -> io::Result<usize> {
+        if let Some(gif) = self.gif {
+            gif.finish()?;
+        }
+        Ok(self.written)
+    }
+}
+
+/// Runs `visualise` on the day's input, saving its frames to `output`: a GIF file, or a
+/// directory of PNG or PPM files. By default these are named after the day, in the current
+/// directory.
+pub fn render(
+    d: &Entry,
+    visualise: Visualiser,
+    input: Option<Source>,
+    output: Option<PathBuf>,
+    options: &Options,
+) -> ExitCode {
+    let source = input.unwrap_or(Source::Default);
+    let data = match source.read(&d.dir()) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("{}/{:02}: {}", d.year, d.day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if data.is_example() && matches!(source, Source::Default) {
+        eprintln!("{}/{:02}: No data/input.txt, using data/example.txt", d.year, d.day);
+    }
+
+    let path = output.unwrap_or_else(|| match options.format {
+        Format::Gif => PathBuf::from(format!("{}-{:02}.gif", d.year, d.day)),
+        _ => PathBuf::from(format!("{}-{:02}", d.year, d.day)),
+    });
+    if options.format != Format::Gif {
+        if let Err(e) = fs::create_dir_all(&path) {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let mut writer = Writer {
+        path: &path,
+        options,
+        gif: None,
+        written: 0,
+    };
+    // Each frame is held back until the next arrives, so the last can always be kept.
+    let mut pending: Option<Frame> = None;
+    let mut count = 0;
+    let mut error = None;
+    let result = visualise(&data.text, &mut |frame| {
+        if let Some(last) = pending.replace(frame.clone()) {
+            if (count - 1) % options.every == 0 && error.is_none() {
+                error = writer.write(&last).err();
+            }
+        }
+        count += 1;
+    });
+    if let Some(last) = pending.filter(|_| error.is_none()) {
+        error = writer.write(&last).err();
+    }
+
+    if let Err(e) = result {
+        eprintln!("{}", e.for_day(d.year, d.day));
+        return ExitCode::FAILURE;
+    }
+    match error.map_or_else(|| writer.finish(), Err) {
+        Ok(written) => {
+            println!("Wrote {} of {} frames to {}", written, count, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,428 @@
+//! Creates the crate for a new day and registers it with the runner.
+
+use crate::registry::workspace_dir;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const LIB_RS: &str = r#"use aoc_core::parse::{self, ParseError};
+use aoc_core::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    /// The lines of the puzzle input.
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        Ok(parse::lines(input).map(|l| l.text()).collect())
+    }
+
+    fn part1(_lines: &Vec<&str>) -> usize {
+        todo!()
+    }
+
+    fn part2(_lines: &Vec<&str>) -> usize {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use aoc_core::{example, Solution};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(0, Day::part1(&Day::parse(&example!()).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(0, Day::part2(&Day::parse(&example!()).unwrap()));
+    }
+}
+"#;
+
+const ANSWERS_TOML: &str = "[example]
+# part1 =
+# part2 =
+";
+
+fn cargo_toml(year: u16, day: u8) -> String {
+    format!(
+        r#"[package]
+name = "aoc-{year}-{day:02}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc-core = {{ path = "../../aoc-core" }}
+
+[lints]
+workspace = true
+"#
+    )
+}
+
+fn fuzz_target(year: u16, day: u8) -> String {
+    format!(
+        "#![no_main]
+
+aoc_fuzz::target!(aoc_{year}_{day:02}::Day);
+"
+    )
+}
+
+/// Inserts `line` among the lines for which `key` returns a value, keeping them sorted by key.
+/// Returns `None` if there is already a line with the same key.
+fn insert_sorted(text: &str, line: &str, key: impl Fn(&str) -> Option<&str>) -> Option<String> {
+    let new_key = key(line).expect("Line to insert has no key");
+    let lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l).map(|k| (i, k)))
+        .collect();
+    if keyed.iter().any(|&(_, k)| k == new_key) {
+        return None;
+    }
+    let at = match keyed.iter().find(|&&(_, k)| k > new_key) {
+        Some(&(i, _)) => i,
+        None => keyed.last().map_or(lines.len(), |&(i, _)| i + 1),
+    };
+    let mut out: Vec<&str> = lines[..at].to_vec();
+    out.push(line);
+    out.extend(&lines[at..]);
+    Some(out.join("\n") + "\n")
+}
+
+/// The name of the day crate a line of `aoc/Cargo.toml` depends on.
+fn dependency_key(line: &str) -> Option<&str> {
+    line.split_once(" = ").map(|(k, _)| k).filter(|k| k.starts_with("aoc-2"))
+}
+
+/// The name of the day crate a line of the registry's `days!` list refers to.
+fn registry_key(line: &str) -> Option<&str> {
+    let (_, k) = line.trim().strip_suffix(',')?.split_once(" => ")?;
+    k.starts_with("aoc_").then_some(k)
+}
+
+/// The workspace member pattern for a year, in the root `Cargo.toml`. Returns `None` if the
+/// year is already a member.
+fn add_workspace_member(text: &str, year: u16) -> Result<Option<String>, String> {
+    let member = format!("\"{}/*\"", year);
+    if text.contains(&member) {
+        return Ok(None);
+    }
+    let Some(start) = text.find("members = [") else {
+        return Err("no workspace members list".to_string());
+    };
+    let Some(end) = text[start..].find(']').map(|end| start + end) else {
+        return Err("unterminated workspace members list".to_string());
+    };
+    Ok(Some(format!("{}, {}{}", &text[..end], member, &text[end..])))
+}
+
+/// The line of a `Cargo.toml` that depends on a day's crate.
+fn dependency(year: u16, day: u8) -> String {
+    format!("aoc-{year}-{day:02} = {{ path = \"../{year}/{day:02}\" }}")
+}
+
+/// The `[[bin]]` section for a day's fuzz target, appended to `fuzz/Cargo.toml`.
+fn add_fuzz_target(text: &str, year: u16, day: u8) -> Option<String> {
+    let name = format!("parse_{year}_{day:02}");
+    if text.contains(&format!("name = \"{}\"", name)) {
+        return None;
+    }
+    Some(format!(
+        "{}\n\n[[bin]]\nname = \"{name}\"\npath = \"fuzz_targets/{name}.rs\"\n\
+         test = false\ndoc = false\nbench = false\n",
+        text.trim_end()
+    ))
+}
+
+/// Adds a day's dependency and its fuzz target to `fuzz/Cargo.toml`, each only if it isn't
+/// there already. Returns `None` if both are.
+fn add_fuzz_day(text: &str, year: u16, day: u8) -> Option<String> {
+    let with_dependency = insert_sorted(text, &dependency(year, day), dependency_key);
+    let text = with_dependency.as_deref().unwrap_or(text);
+    add_fuzz_target(text, year, day).or(with_dependency)
+}
+
+/// A file that `new_day` is going to write.
+struct Change {
+    path: PathBuf,
+    contents: String,
+    /// Whether the file is new, rather than an edit of one that's already there.
+    created: bool,
+}
+
+fn created(path: PathBuf, contents: impl Into<String>) -> Change {
+    Change {
+        path,
+        contents: contents.into(),
+        created: true,
+    }
+}
+
+/// Applies `edit` to the file at `path`, giving the change to write if anything changed.
+fn edited(
+    path: PathBuf,
+    edit: impl FnOnce(&str) -> Result<Option<String>, String>,
+) -> Result<Option<Change>, String> {
+    let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let contents = edit(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(contents.map(|contents| Change {
+        path,
+        contents,
+        created: false,
+    }))
+}
+
+/// `path` with `suffix` added to its file name: where a change is written before being moved
+/// into place, or where the file it replaces is kept until every change is in place.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Moves `staged` to `path`, keeping any file already there as a backup, whose path is
+/// returned.
+fn replace(path: &Path, staged: &Path) -> io::Result<Option<PathBuf>> {
+    let backup = with_suffix(path, ".old");
+    let backup = match fs::rename(path, &backup) {
+        Ok(()) => Some(backup),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+    if let Err(e) = fs::rename(staged, path) {
+        if let Some(backup) = &backup {
+            let _ = fs::rename(backup, path);
+        }
+        return Err(e);
+    }
+    Ok(backup)
+}
+
+/// Writes every change next to where it belongs, then moves them all into place, so that
+/// failing to write or move any of them leaves the files as they were.
+fn apply(changes: &[Change]) -> Result<(), String> {
+    let mut staged = vec![];
+    for change in changes {
+        let path = with_suffix(&change.path, ".new");
+        let dir = change.path.parent().unwrap_or(Path::new("."));
+        let written = fs::create_dir_all(dir).and_then(|_| fs::write(&path, &change.contents));
+        if let Err(e) = written {
+            for path in staged {
+                let _ = fs::remove_file(path);
+            }
+            return Err(format!("{}: {}", change.path.display(), e));
+        }
+        staged.push(path);
+    }
+    let mut moved = vec![];
+    for (change, path) in changes.iter().zip(&staged) {
+        match replace(&change.path, path) {
+            Ok(backup) => moved.push((change, backup)),
+            Err(e) => {
+                for (change, backup) in moved.into_iter().rev() {
+                    let _ = match backup {
+                        Some(backup) => fs::rename(backup, &change.path),
+                        None => fs::remove_file(&change.path),
+                    };
+                }
+                for path in staged {
+                    let _ = fs::remove_file(path);
+                }
+                return Err(format!("{}: {}", change.path.display(), e));
+            }
+        }
+    }
+    for (change, backup) in moved {
+        if let Some(backup) = backup {
+            let _ = fs::remove_file(backup);
+        }
+        let verb = if change.created { "Created" } else { "Updated" };
+        println!("{} {}", verb, change.path.display());
+    }
+    Ok(())
+}
+
+/// Creates `<year>/<day>` with a `Solution` skeleton, a test module reading `data/example.txt`
+/// and an empty `data/answers.toml`, then adds it to the workspace, the runner's dependencies,
+/// the registry and the fuzz targets. Every edit is worked out before anything is written, so
+/// a manifest that can't be edited leaves the workspace as it was.
+pub fn new_day(year: u16, day: u8) -> Result<(), String> {
+    let root = workspace_dir();
+    let dir = root.join(year.to_string()).join(format!("{:02}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let target = format!("fuzz/fuzz_targets/parse_{year}_{day:02}.rs");
+    let entry = format!("    {year} {day} => aoc_{year}_{day:02},");
+    let mut changes = vec![
+        created(dir.join("Cargo.toml"), cargo_toml(year, day)),
+        created(dir.join("src/lib.rs"), LIB_RS),
+        created(dir.join("data/example.txt"), ""),
+        created(dir.join("data/answers.toml"), ANSWERS_TOML),
+        created(root.join(target), fuzz_target(year, day)),
+    ];
+    let edits = [
+        edited(root.join("Cargo.toml"), |text| add_workspace_member(text, year))?,
+        edited(root.join("aoc/Cargo.toml"), |text| {
+            Ok(insert_sorted(text, &dependency(year, day), dependency_key))
+        })?,
+        edited(root.join("fuzz/Cargo.toml"), |text| Ok(add_fuzz_day(text, year, day)))?,
+        edited(root.join("aoc/src/registry.rs"), |text| {
+            Ok(insert_sorted(text, &entry, registry_key))
+        })?,
+    ];
+    changes.extend(edits.into_iter().flatten());
+    if let Err(e) = apply(&changes) {
+        let _ = fs::remove_dir_all(&dir);
+        return Err(e);
+    }
+
+    println!(
+        "Save the puzzle input as {} and the example as {}",
+        dir.join("data/input.txt").display(),
+        dir.join("data/example.txt").display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        add_fuzz_day, add_fuzz_target, add_workspace_member, apply, created, dependency_key, edited,
+        insert_sorted, registry_key,
+    };
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_insert_dependency() {
+        let text = "[dependencies]\nclap = \"4\"\naoc-2022-01 = {}\naoc-2023-01 = {}\n";
+        assert_eq!(
+            Some(
+                "[dependencies]\nclap = \"4\"\naoc-2022-01 = {}\naoc-2022-02 = {}\naoc-2023-01 = {}\n"
+                    .to_string()
+            ),
+            insert_sorted(text, "aoc-2022-02 = {}", dependency_key)
+        );
+        assert_eq!(
+            Some(
+                "[dependencies]\nclap = \"4\"\naoc-2022-01 = {}\naoc-2023-01 = {}\naoc-2024-01 = {}\n"
+                    .to_string()
+            ),
+            insert_sorted(text, "aoc-2024-01 = {}", dependency_key)
+        );
+        assert_eq!(None, insert_sorted(text, "aoc-2023-01 = {}", dependency_key));
+    }
+
+    #[test]
+    fn test_insert_registry_entry() {
+        let text = "days! {\n    2023 9 => aoc_2023_09,\n    2023 11 => aoc_2023_11,\n}\n";
+        assert_eq!(
+            Some(
+                "days! {\n    2023 9 => aoc_2023_09,\n    2023 10 => aoc_2023_10,\n    2023 11 => aoc_2023_11,\n}\n"
+                    .to_string()
+            ),
+            insert_sorted(text, "    2023 10 => aoc_2023_10,", registry_key)
+        );
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        let text = "[workspace]\nmembers = [\"aoc\", \"2023/*\"]\n";
+        assert_eq!(
+            Ok(Some(
+                "[workspace]\nmembers = [\"aoc\", \"2023/*\", \"2024/*\"]\n".to_string()
+            )),
+            add_workspace_member(text, 2024)
+        );
+        assert_eq!(Ok(None), add_workspace_member(text, 2023));
+        assert!(add_workspace_member("[workspace]\n", 2024).is_err());
+    }
+
+    #[test]
+    fn test_add_fuzz_target() {
+        let text = "[[bin]]\nname = \"parse_2023_25\"\n";
+        assert_eq!(
+            Some(
+                "[[bin]]\nname = \"parse_2023_25\"\n\n[[bin]]\nname = \"parse_2024_01\"\n\
+                 path = \"fuzz_targets/parse_2024_01.rs\"\n\
+                 test = false\ndoc = false\nbench = false\n"
+                    .to_string()
+            ),
+            add_fuzz_target(text, 2024, 1)
+        );
+        assert_eq!(None, add_fuzz_target(text, 2023, 25));
+    }
+
+    #[test]
+    fn test_add_fuzz_day() {
+        // The target is still added when the dependency is already there.
+        let text = "[dependencies]\naoc-2024-01 = { path = \"../2024/01\" }\n";
+        let added = add_fuzz_day(text, 2024, 1).unwrap();
+        assert!(added.starts_with(text));
+        assert!(added.contains("name = \"parse_2024_01\""));
+        assert_eq!(None, add_fuzz_day(&added, 2024, 1));
+
+        let added = add_fuzz_day("[dependencies]\n", 2024, 1).unwrap();
+        assert!(added.contains("aoc-2024-01 = "));
+        assert!(added.contains("name = \"parse_2024_01\""));
+    }
+
+    #[test]
+    fn test_apply_all_or_nothing() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("file"), "").unwrap();
+        let changes = [
+            created(dir.join("a.txt"), "a"),
+            // Can't be written, because its directory is a file.
+            created(dir.join("file/b.txt"), "b"),
+        ];
+        assert!(apply(&changes).is_err());
+        let left: Vec<_> = fs::read_dir(&dir).unwrap().map(|e| e.unwrap().file_name()).collect();
+        assert_eq!(vec!["file"], left);
+
+        assert_eq!(Ok(()), apply(&changes[..1]));
+        assert_eq!("a", fs::read_to_string(dir.join("a.txt")).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_apply_rolls_back() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-rollback-{}", std::process::id()));
+        fs::create_dir_all(dir.join("c.txt.old/dir")).unwrap();
+        fs::write(dir.join("a.txt"), "old").unwrap();
+        fs::write(dir.join("c.txt"), "old").unwrap();
+        let changes = [
+            created(dir.join("b.txt"), "b"),
+            edited(dir.join("a.txt"), |_| Ok(Some("new".into()))).unwrap().unwrap(),
+            // Can't be moved into place, because its backup can't be made over a directory.
+            edited(dir.join("c.txt"), |_| Ok(Some("new".into()))).unwrap().unwrap(),
+        ];
+        assert!(apply(&changes).is_err());
+        let mut left: Vec<_> =
+            fs::read_dir(&dir).unwrap().map(|e| e.unwrap().file_name()).collect();
+        left.sort();
+        assert_eq!(vec!["a.txt", "c.txt", "c.txt.old"], left);
+        assert_eq!("old", fs::read_to_string(dir.join("a.txt")).unwrap());
+        assert_eq!("old", fs::read_to_string(dir.join("c.txt")).unwrap());
+
+        assert_eq!(Ok(()), apply(&changes[..2]));
+        let mut left: Vec<_> =
+            fs::read_dir(&dir).unwrap().map(|e| e.unwrap().file_name()).collect();
+        left.sort();
+        assert_eq!(vec!["a.txt", "b.txt", "c.txt", "c.txt.old"], left);
+        assert_eq!("new", fs::read_to_string(dir.join("a.txt")).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,175 @@
+//! Checks every solution against the answers recorded in `data/answers.toml`.
+
+use crate::answers::{self, Answers};
+use crate::registry::Entry;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// The outcome of checking one part against one or more inputs.
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Status {
+    /// Combines the results for several inputs: any failure fails, otherwise any pass passes.
+    fn combine(self, other: Status) -> Status {
+        match (self, other) {
+            (Status::Fail, _) | (_, Status::Fail) => Status::Fail,
+            (Status::Pass, _) | (_, Status::Pass) => Status::Pass,
+            _ => Status::Missing,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "-",
+        }
+    }
+}
+
+/// The names (without `.txt`) of the day's inputs in `data/`: `input` first, then the examples.
+pub fn input_names(day_dir: &Path) -> Vec<String> {
+    let mut examples: Vec<String> = fs::read_dir(day_dir.join("data"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| {
+            let name = e.file_name().to_string_lossy().into_owned();
+            let stem = name.strip_suffix(".txt")?;
+            stem.starts_with("example").then(|| stem.to_string())
+        })
+        .collect();
+    examples.sort();
+    let mut names = vec![];
+    if day_dir.join("data/input.txt").is_file() {
+        names.push("input".to_string());
+    }
+    names.extend(examples);
+    names
+}
+
+/// Runs the parts of `d` with recorded answers against the named input, returning the status of
+/// each part and describing any failures in `failures`.
+fn check(d: &Entry, answers: &Answers, name: &str, failures: &mut Vec<String>) -> [Status; 2] {
+    let mut status = [Status::Missing; 2];
+    let expected: Vec<(u8, String)> = (1..=2)
+        .filter_map(|p| answers.get(name, p).map(|a| (p, a)))
+        .collect();
+    if expected.is_empty() {
+        return status;
+    }
+
+    let describe = |p: u8| format!("{}/{:02} {} part {}", d.year, d.day, name, p);
+    let path = d.dir().join("data").join(format!("{}.txt", name));
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => {
+            for &(p, _) in &expected {
+                status[p as usize - 1] = Status::Fail;
+                failures.push(format!("{}: {}: {}", describe(p), path.display(), e));
+            }
+            return status;
+        }
+    };
+
+    // Each part runs separately, so a panic in one doesn't hide the result of the other.
+    for (p, want) in expected {
+        let i = p as usize - 1;
+        match panic::catch_unwind(|| d.run(&[p], &text).map(|mut r| r.parts.remove(0).0)) {
+            Ok(Ok(got)) if answers::matches(&got, &want) => status[i] = Status::Pass,
+            Ok(Ok(got)) => {
+                status[i] = Status::Fail;
+                failures.push(format!(
+                    "{}: expected {}, got {}",
+                    describe(p),
+                    want.trim_end(),
+                    got.trim_end()
+                ));
+            }
+            Ok(Err(e)) => {
+                status[i] = Status::Fail;
+                failures.push(format!("{}: {}: {}", describe(p), path.display(), e));
+            }
+            Err(payload) => {
+                let msg = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                status[i] = Status::Fail;
+                failures.push(format!("{}: panicked: {}", describe(p), msg));
+            }
+        }
+    }
+    status
+}
+
+/// Prints a pass/fail/missing matrix of the real input and examples for each day, followed by
+/// the details of any failures.
+pub fn verify(days: &[&Entry]) -> ExitCode {
+    // Panics are reported as failures, so keep their messages out of the matrix.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut failures = vec![];
+    let mut counts = [0; 3];
+    println!("day      input 1  input 2  examples 1  examples 2");
+    for d in days {
+        let dir = d.dir();
+        let answers = match Answers::load(&dir) {
+            Ok(answers) => answers,
+            Err(e) => {
+                failures.push(e);
+                Answers::default()
+            }
+        };
+
+        let mut input = [Status::Missing; 2];
+        let mut examples = [Status::Missing; 2];
+        for name in input_names(&dir) {
+            let status = check(d, &answers, &name, &mut failures);
+            let target = if name == "input" { &mut input } else { &mut examples };
+            for (t, s) in target.iter_mut().zip(status) {
+                *t = t.combine(s);
+            }
+        }
+
+        let row = [input[0], input[1], examples[0], examples[1]];
+        for s in row {
+            counts[s as usize] += 1;
+        }
+        println!(
+            "{}/{:02}  {:<9}{:<9}{:<12}{}",
+            d.year,
+            d.day,
+            row[0].label(),
+            row[1].label(),
+            row[2].label(),
+            row[3].label()
+        );
+    }
+    let _ = panic::take_hook();
+
+    println!();
+    for f in &failures {
+        println!("{}", f);
+    }
+    println!(
+        "{} passed, {} failed, {} missing",
+        counts[Status::Pass as usize],
+        counts[Status::Fail as usize],
+        counts[Status::Missing as usize]
+    );
+
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
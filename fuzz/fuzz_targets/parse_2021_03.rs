@@ -0,0 +1,3 @@
+#![no_main]
+
+aoc_fuzz::target!(aoc_2021_03::Day);
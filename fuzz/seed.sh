@@ -0,0 +1,21 @@
+#!/bin/sh
+# Seeds each fuzz target's corpus with its day's examples, e.g. fuzz/corpus/parse_2023_22/, along
+# with copies that start and end with multi-byte characters, and any inputs that have crashed it
+# before, from fuzz/regressions/.
+set -e
+cd "$(dirname "$0")/.."
+for data in 20*/*/data; do
+    day=${data%/data}
+    target=parse_$(echo "$day" | tr / _)
+    corpus=fuzz/corpus/$target
+    mkdir -p "$corpus"
+    for example in "$data"/example*.txt; do
+        if [ -f "$example" ]; then
+            name=$(basename "$example" .txt)
+            cp "$example" "$corpus/"
+            sed '1s/^./é/' "$example" > "$corpus/$name-utf8-start.txt"
+            sed '$s/$/𝄞/' "$example" > "$corpus/$name-utf8-end.txt"
+        fi
+    done
+    if [ -d "fuzz/regressions/$target" ]; then cp "fuzz/regressions/$target"/* "$corpus/"; fi
+done
@@ -0,0 +1,103 @@
+//! The harness shared by the fuzz targets, one per day, which feed arbitrary bytes to the day's
+//! `Solution::parse`.
+//!
+//! Malformed input has to be reported as a `ParseError`, so libFuzzer flags any panic, including
+//! overflows and out-of-bounds indexing. The error also has to point somewhere in the input.
+//!
+//! Most of libFuzzer's byte-level mutations of a multi-byte character leave invalid UTF-8, which
+//! is skipped, so the targets' mutator also swaps characters for multi-byte ones. Otherwise the
+//! parsers would hardly ever see text that isn't ASCII.
+
+use aoc_core::Solution;
+
+pub use libfuzzer_sys;
+
+/// The fuzz target and mutator for a day's `Solution`.
+#[macro_export]
+macro_rules! target {
+    ($day:ty) => {
+        $crate::libfuzzer_sys::fuzz_target!(|data: &[u8]| $crate::parse::<$day>(data));
+        $crate::libfuzzer_sys::fuzz_mutator!(
+            |data: &mut [u8], size: usize, max_size: usize, seed: u32| {
+                $crate::mutate(data, size, max_size, seed)
+            }
+        );
+    };
+}
+
+/// Parses `data` as the input to `S`, checking that any error is at a real line and column.
+/// Inputs are always text, so bytes that aren't UTF-8 are skipped.
+pub fn parse<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Err(e) = S::parse(input) {
+        let lines: Vec<&str> = input.lines().collect();
+        assert!((1..=lines.len() + 1).contains(&e.line), "{}: no such line", e);
+        let width = lines.get(e.line - 1).map_or(0, |l| l.chars().count());
+        assert!((1..=width + 1).contains(&e.column), "{}: no such column", e);
+    }
+}
+
+/// Characters of two, three and four bytes in UTF-8, including ones that look like spaces.
+const WIDE: [&str; 6] = ["é", "\u{a0}", "€", "\u{feff}", "𝄞", "\u{301}"];
+
+/// Mutates the first `size` bytes of `data` as libFuzzer would, then one time in four swaps one
+/// of their characters for a multi-byte one. Returns the new size, at most `max_size`.
+pub fn mutate(data: &mut [u8], size: usize, max_size: usize, seed: u32) -> usize {
+    let size = libfuzzer_sys::fuzzer_mutate(data, size, max_size);
+    // Xorshift, to get a few independent choices from the seed.
+    let mut state = seed.max(1);
+    let mut choose = |n: usize| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as usize % n
+    };
+    if choose(4) != 0 {
+        return size;
+    }
+    let wide = WIDE[choose(WIDE.len())];
+    let at = choose(size.max(1));
+    put_wide(data, size, max_size, at, wide)
+}
+
+/// Replaces character number `at` (or the end of the text, if there are fewer) in the first
+/// `size` bytes of `data` with `wide`, returning the new size. Leaves `data` alone if it isn't
+/// UTF-8, or if the result would be longer than `max_size`.
+fn put_wide(data: &mut [u8], size: usize, max_size: usize, at: usize, wide: &str) -> usize {
+    let Ok(text) = std::str::from_utf8(&data[..size]) else {
+        return size;
+    };
+    let (start, end) = match text.char_indices().nth(at) {
+        Some((i, c)) => (i, i + c.len_utf8()),
+        None => (size, size),
+    };
+    let new_size = size - (end - start) + wide.len();
+    if new_size > max_size.min(data.len()) {
+        return size;
+    }
+    data.copy_within(end..size, start + wide.len());
+    data[start..start + wide.len()].copy_from_slice(wide.as_bytes());
+    new_size
+}
+
+#[cfg(test)]
+mod test {
+    use super::put_wide;
+
+    #[test]
+    fn test_put_wide() {
+        let mut data = *b"[A] [B]\0\0\0";
+        assert_eq!(8, put_wide(&mut data, 7, 10, 1, "é"));
+        assert_eq!("[é] [B]", std::str::from_utf8(&data[..8]).unwrap());
+        assert_eq!(9, put_wide(&mut data, 8, 10, 1, "€"));
+        assert_eq!("[€] [B]", std::str::from_utf8(&data[..9]).unwrap());
+        // No room for a longer character.
+        assert_eq!(9, put_wide(&mut data, 9, 10, 0, "𝄞"));
+
+        let mut data = *b"ab\0\0";
+        assert_eq!(4, put_wide(&mut data, 2, 4, 9, "é"));
+        assert_eq!("abé", std::str::from_utf8(&data).unwrap());
+    }
+}